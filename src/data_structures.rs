@@ -1,4 +1,7 @@
 pub mod matrix;
 pub mod queue;
 pub mod stack;
-pub mod heap;
\ No newline at end of file
+pub mod heap;
+pub mod circular_buffer;
+pub mod deque;
+pub mod sparse;
\ No newline at end of file
@@ -0,0 +1,213 @@
+use std::collections::VecDeque;
+
+use crate::data_structures::queue::IsQueue;
+use crate::error::Error;
+
+/// A fixed-capacity queue (FIFO) that overwrites its oldest element when full.
+///
+/// Adding to a full `CircularBuffer` removes the oldest element to make room
+/// and returns it, so `add` never fails.
+///
+/// A buffer can optionally be filled with a default value. In that mode the
+/// buffer always holds `capacity` elements: it starts full of defaults, and
+/// removing an element adds a new default to the back, so `size` is always
+/// equal to `capacity`.
+///
+/// # Type parameters
+/// - `T`: Generic type that implements the `Clone` trait, used to refill
+///   a buffer created with a default value.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::circular_buffer::*;
+/// # use crate::algorithms::data_structures::queue::IsQueue;
+/// let mut buffer = CircularBuffer::new(3);
+///
+/// assert_eq!(buffer.add(1).unwrap(), None);
+/// assert_eq!(buffer.add(2).unwrap(), None);
+/// assert_eq!(buffer.add(3).unwrap(), None);
+///
+/// // The buffer is full, so the oldest element is evicted
+/// assert_eq!(buffer.add(4).unwrap(), Some(1));
+///
+/// assert_eq!(buffer.remove().unwrap(), 2);
+/// assert_eq!(buffer.size(), 2);
+/// ```
+#[derive(Debug)]
+pub struct CircularBuffer<T: Clone> {
+    buffer: VecDeque<T>,
+    capacity: usize,
+    default: Option<T>,
+}
+
+impl<T: Clone> CircularBuffer<T> {
+    /// Creates a new empty `CircularBuffer` holding at most `capacity` elements.
+    ///
+    /// # Returns
+    /// Returns an empty `CircularBuffer<T>`.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::circular_buffer::*;
+    /// # use crate::algorithms::data_structures::queue::IsQueue;
+    /// let buffer: CircularBuffer<isize> = CircularBuffer::new(5);
+    ///
+    /// assert_eq!(buffer.size(), 0);
+    /// assert_eq!(buffer.capacity(), 5);
+    /// ```
+    pub fn new(capacity: usize) -> CircularBuffer<T> {
+        assert!(capacity > 0);
+
+        CircularBuffer {
+            buffer: VecDeque::with_capacity(capacity),
+            capacity,
+            default: None,
+        }
+    }
+
+    /// Creates a new `CircularBuffer` filled with `capacity` copies of `default`.
+    ///
+    /// Removing an element from the buffer adds a new `default` value to the
+    /// back, so the buffer is never empty.
+    ///
+    /// # Returns
+    /// Returns a full `CircularBuffer<T>`.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::circular_buffer::*;
+    /// # use crate::algorithms::data_structures::queue::IsQueue;
+    /// let mut buffer = CircularBuffer::with_default(3, 0);
+    /// assert_eq!(buffer.size(), 3);
+    ///
+    /// assert_eq!(buffer.add(7).unwrap(), Some(0));
+    /// assert_eq!(buffer.remove().unwrap(), 0);
+    /// assert_eq!(buffer.remove().unwrap(), 0);
+    /// assert_eq!(buffer.remove().unwrap(), 7);
+    /// assert_eq!(buffer.remove().unwrap(), 0);
+    /// assert_eq!(buffer.size(), 3);
+    /// ```
+    pub fn with_default(capacity: usize, default: T) -> CircularBuffer<T> {
+        assert!(capacity > 0);
+
+        CircularBuffer {
+            buffer: std::iter::repeat_n(default.clone(), capacity).collect(),
+            capacity,
+            default: Some(default),
+        }
+    }
+
+    /// Gets the maximum number of elements the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns `true` if the buffer holds `capacity` elements, meaning the
+    /// next `add` will evict the oldest element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::circular_buffer::*;
+    /// # use crate::algorithms::data_structures::queue::IsQueue;
+    /// let mut buffer = CircularBuffer::new(1);
+    /// assert!(!buffer.is_full());
+    ///
+    /// buffer.add(42);
+    /// assert!(buffer.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.buffer.len() == self.capacity
+    }
+}
+
+impl<T: Clone> IsQueue<T> for CircularBuffer<T> {
+    /// Adds a new element to the end of the `CircularBuffer`.
+    ///
+    /// # Parameters
+    /// - `value`: Value to add to the buffer
+    ///
+    /// # Returns
+    /// - `Some(T)`: The oldest element, if the buffer was full
+    /// - `None`: If the buffer had room for the new element
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::circular_buffer::*;
+    /// # use crate::algorithms::data_structures::queue::IsQueue;
+    /// let mut buffer = CircularBuffer::new(1);
+    /// assert_eq!(buffer.add(1).unwrap(), None);
+    /// assert_eq!(buffer.add(2).unwrap(), Some(1));
+    /// ```
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        let evicted = if self.is_full() { self.buffer.pop_front() } else { None };
+        self.buffer.push_back(value);
+        Ok(evicted)
+    }
+
+    /// Removes the oldest value from the `CircularBuffer` and returns it.
+    ///
+    /// If the buffer was created with a default value, the default is added
+    /// to the back of the buffer to replace the removed element.
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The oldest value in the buffer
+    /// - `Err(Error::Empty)`: If the buffer is empty
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `CircularBuffer` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::circular_buffer::*;
+    /// # use crate::algorithms::data_structures::queue::IsQueue;
+    /// # use crate::algorithms::error::Error;
+    /// let mut buffer = CircularBuffer::new(2);
+    /// buffer.add(42);
+    /// assert_eq!(buffer.remove().unwrap(), 42);
+    /// assert_eq!(buffer.remove(), Err(Error::Empty));
+    /// ```
+    fn remove(&mut self) -> Result<T, Error> {
+        let value = self.buffer.pop_front().ok_or(Error::Empty)?;
+        if let Some(default) = &self.default {
+            self.buffer.push_back(default.clone());
+        }
+        Ok(value)
+    }
+
+    /// Gets a reference to the oldest value in the `CircularBuffer`.
+    ///
+    /// # Returns
+    /// - `Ok(&T)`: The oldest value in the buffer
+    /// - `Err(Error::Empty)`: If the buffer is empty
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `CircularBuffer` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::circular_buffer::*;
+    /// # use crate::algorithms::data_structures::queue::IsQueue;
+    /// let mut buffer = CircularBuffer::new(2);
+    /// buffer.add(42);
+    /// buffer.add(43);
+    /// assert_eq!(buffer.peek().unwrap(), &42);
+    /// ```
+    fn peek(&self) -> Result<&T, Error> {
+        self.buffer.front().ok_or(Error::Empty)
+    }
+
+    /// Gets the size of the `CircularBuffer`.
+    ///
+    /// # Returns
+    /// Returns the number of elements in the buffer, including default values.
+    fn size(&self) -> usize {
+        self.buffer.len()
+    }
+}
@@ -0,0 +1,439 @@
+use std::collections::VecDeque;
+
+use crate::data_structures::queue::IsQueue;
+use crate::data_structures::stack::IsStack;
+use crate::error::Error;
+
+/// A double-ended queue with a growable size and no capacity limit.
+///
+/// Elements can be added, removed and read at both the front and the back
+/// in amortised O(1), and accessed by index in O(1). Index `0` is the front.
+///
+/// A `Deque` can be used wherever an `IsStack` or `IsQueue` is expected by
+/// wrapping it in an `AsStack` or `AsQueue` adapter.
+///
+/// # Type parameters
+/// - `T`: Generic type
+///
+/// # Examples
+///
+/// This example uses the `deque!` macro to add elements to the back of the
+/// deque, from left to right.
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::deque::*;
+/// # fn main() {
+/// let mut deque = deque![2, 3, 4];
+///
+/// deque.push_front(1).unwrap();
+/// deque.push_back(5).unwrap();
+///
+/// assert_eq!(deque.pop_front().unwrap(), 1);
+/// assert_eq!(deque.pop_back().unwrap(), 5);
+///
+/// assert_eq!(deque.peek_front().unwrap(), &2);
+/// assert_eq!(deque.get(1), Some(&3));
+/// assert_eq!(deque.len(), 3);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deque<T> {
+    deque: VecDeque<T>
+}
+
+/// Defines methods expected on a double-ended queue data structure
+pub trait IsDeque<T> {
+    /// Adds a new value to the front of the deque.
+    ///
+    /// # Returns
+    /// - `Ok(_)`: If the element add was successful.
+    ///     - `Some(T)`: If adding an element resulted in the removal of an
+    ///       existing one
+    ///     - `None`: Adding an element did not return any value
+    /// - `Err(Error::Full)`: If the element add was unsuccessful
+    fn push_front(&mut self, value: T) -> Result<Option<T>, Error>;
+
+    /// Adds a new value to the back of the deque.
+    ///
+    /// # Returns
+    /// - `Ok(_)`: If the element add was successful.
+    ///     - `Some(T)`: If adding an element resulted in the removal of an
+    ///       existing one
+    ///     - `None`: Adding an element did not return any value
+    /// - `Err(Error::Full)`: If the element add was unsuccessful
+    fn push_back(&mut self, value: T) -> Result<Option<T>, Error>;
+
+    /// Removes the value at the front of the deque and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the deque is empty.
+    fn pop_front(&mut self) -> Result<T, Error>;
+
+    /// Removes the value at the back of the deque and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the deque is empty.
+    fn pop_back(&mut self) -> Result<T, Error>;
+
+    /// Gets a reference to the value at the front of the deque.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the deque is empty.
+    fn peek_front(&self) -> Result<&T, Error>;
+
+    /// Gets a reference to the value at the back of the deque.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the deque is empty.
+    fn peek_back(&self) -> Result<&T, Error>;
+
+    /// Gets a reference to the value at `index`, counting from the front.
+    ///
+    /// # Returns
+    /// Returns `None` if `index` is outside the deque.
+    fn get(&self, index: usize) -> Option<&T>;
+
+    /// Gets the number of elements in the deque.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the deque contains no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Deque<T> {
+    /// Creates a new empty `Deque`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::deque::*;
+    /// let deque: Deque<isize> = Deque::new();
+    ///
+    /// assert_eq!(deque.len(), 0);
+    /// ```
+    pub fn new() -> Deque<T> {
+        Deque { deque: VecDeque::new() }
+    }
+
+    /// Creates a new empty `Deque` with space for at least `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Deque<T> {
+        Deque { deque: VecDeque::with_capacity(capacity) }
+    }
+
+    /// Gets a mutable reference to the value at `index`, counting from the front.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::deque::*;
+    /// let mut deque: Deque<isize> = Deque::new();
+    /// deque.push_back(1);
+    ///
+    /// *deque.get_mut(0).unwrap() = 5;
+    /// assert_eq!(deque.get(0), Some(&5));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.deque.get_mut(index)
+    }
+
+    /// Returns an iterator over the deque, from front to back.
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, T> {
+        self.deque.iter()
+    }
+
+    /// Rotates the deque `n` places to the left.
+    ///
+    /// The first `n` elements are moved to the back, in order. Rotating by
+    /// more than the length of the deque wraps around.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::deque::*;
+    /// let mut deque: Deque<isize> = (1..=5).collect();
+    /// deque.rotate_left(2);
+    ///
+    /// assert_eq!(deque.iter().cloned().collect::<Vec<_>>(), [3, 4, 5, 1, 2]);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        if !self.deque.is_empty() {
+            let n = n % self.deque.len();
+            self.deque.rotate_left(n);
+        }
+    }
+
+    /// Rotates the deque `n` places to the right.
+    ///
+    /// The last `n` elements are moved to the front, in order. Rotating by
+    /// more than the length of the deque wraps around.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::deque::*;
+    /// let mut deque: Deque<isize> = (1..=5).collect();
+    /// deque.rotate_right(2);
+    ///
+    /// assert_eq!(deque.iter().cloned().collect::<Vec<_>>(), [4, 5, 1, 2, 3]);
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        if !self.deque.is_empty() {
+            let n = n % self.deque.len();
+            self.deque.rotate_right(n);
+        }
+    }
+
+    /// Splits the deque in two at `at`.
+    ///
+    /// # Returns
+    /// Returns a new `Deque` holding the elements from index `at` onwards,
+    /// leaving the elements before `at` in `self`.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `at` is greater than the length of
+    /// the deque.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::deque::*;
+    /// let mut deque: Deque<isize> = (1..=5).collect();
+    /// let back = deque.split_off(3).unwrap();
+    ///
+    /// assert_eq!(deque.iter().cloned().collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert_eq!(back.iter().cloned().collect::<Vec<_>>(), [4, 5]);
+    /// assert!(deque.split_off(4).is_err());
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Result<Deque<T>, Error> {
+        if at > self.deque.len() {
+            return Err(Error::OutOfBounds { index: at, len: self.deque.len() });
+        }
+
+        Ok(Deque { deque: self.deque.split_off(at) })
+    }
+
+    /// Moves all elements of `other` to the back of the deque, leaving
+    /// `other` empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::deque::*;
+    /// let mut deque: Deque<isize> = (1..=2).collect();
+    /// let mut other: Deque<isize> = (3..=4).collect();
+    /// deque.append(&mut other);
+    ///
+    /// assert_eq!(deque.iter().cloned().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// assert!(other.is_empty());
+    /// ```
+    pub fn append(&mut self, other: &mut Deque<T>) {
+        self.deque.append(&mut other.deque);
+    }
+}
+
+impl<T> Default for Deque<T> {
+    /// Initializes a `Default` `Deque`.
+    ///
+    /// # Returns
+    /// Returns an empty `Deque<T>`
+    fn default() -> Deque<T> {
+        Deque { deque: VecDeque::new() }
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    /// Builds a `Deque` by adding each value to the back, in order.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Deque<T> {
+        Deque { deque: iter.into_iter().collect() }
+    }
+}
+
+impl<T> IsDeque<T> for Deque<T> {
+    /// Adds a new element to the front of the `Deque`.
+    ///
+    /// # Returns
+    /// `None` as the element addition should always be successful.
+    fn push_front(&mut self, value: T) -> Result<Option<T>, Error> {
+        self.deque.push_front(value);
+        Ok(None)
+    }
+
+    /// Adds a new element to the back of the `Deque`.
+    ///
+    /// # Returns
+    /// `None` as the element addition should always be successful.
+    fn push_back(&mut self, value: T) -> Result<Option<T>, Error> {
+        self.deque.push_back(value);
+        Ok(None)
+    }
+
+    fn pop_front(&mut self) -> Result<T, Error> {
+        self.deque.pop_front().ok_or(Error::Empty)
+    }
+
+    fn pop_back(&mut self) -> Result<T, Error> {
+        self.deque.pop_back().ok_or(Error::Empty)
+    }
+
+    fn peek_front(&self) -> Result<&T, Error> {
+        self.deque.front().ok_or(Error::Empty)
+    }
+
+    fn peek_back(&self) -> Result<&T, Error> {
+        self.deque.back().ok_or(Error::Empty)
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        self.deque.get(index)
+    }
+
+    fn len(&self) -> usize {
+        self.deque.len()
+    }
+}
+
+impl<T, D: IsDeque<T>> IsDeque<T> for &mut D {
+    fn push_front(&mut self, value: T) -> Result<Option<T>, Error> {
+        (**self).push_front(value)
+    }
+
+    fn push_back(&mut self, value: T) -> Result<Option<T>, Error> {
+        (**self).push_back(value)
+    }
+
+    fn pop_front(&mut self) -> Result<T, Error> {
+        (**self).pop_front()
+    }
+
+    fn pop_back(&mut self) -> Result<T, Error> {
+        (**self).pop_back()
+    }
+
+    fn peek_front(&self) -> Result<&T, Error> {
+        (**self).peek_front()
+    }
+
+    fn peek_back(&self) -> Result<&T, Error> {
+        (**self).peek_back()
+    }
+
+    fn get(&self, index: usize) -> Option<&T> {
+        (**self).get(index)
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+}
+
+/// Adapts any `IsDeque` into an `IsStack`, using the back of the deque as
+/// the top of the stack.
+///
+/// Wrap a borrowed deque (`AsStack(&mut deque)`) to use it as a stack
+/// temporarily.
+///
+/// # Examples
+/// ```
+/// # use crate::algorithms::data_structures::deque::*;
+/// # use crate::algorithms::data_structures::stack::IsStack;
+/// fn push_all<S: IsStack<isize>>(stack: &mut S) {
+///     for i in 0..3 {
+///         stack.add(i).unwrap();
+///     }
+/// }
+///
+/// let mut deque: Deque<isize> = Deque::new();
+/// push_all(&mut AsStack(&mut deque));
+///
+/// assert_eq!(deque.pop_back().unwrap(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsStack<D>(pub D);
+
+/// Adapts any `IsDeque` into an `IsQueue`, adding to the back of the deque
+/// and removing from the front.
+///
+/// Wrap a borrowed deque (`AsQueue(&mut deque)`) to use it as a queue
+/// temporarily.
+///
+/// # Examples
+/// ```
+/// # use crate::algorithms::data_structures::deque::*;
+/// # use crate::algorithms::data_structures::queue::IsQueue;
+/// let mut queue = AsQueue(Deque::new());
+/// queue.add(1).unwrap();
+/// queue.add(2).unwrap();
+///
+/// assert_eq!(queue.remove().unwrap(), 1);
+/// assert_eq!(queue.0.len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsQueue<D>(pub D);
+
+impl<T, D: IsDeque<T>> IsStack<T> for AsStack<D> {
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        self.0.push_back(value)
+    }
+
+    fn remove(&mut self) -> Result<T, Error> {
+        self.0.pop_back()
+    }
+
+    fn peek(&self) -> Result<&T, Error> {
+        self.0.peek_back()
+    }
+
+    fn size(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<T, D: IsDeque<T>> IsQueue<T> for AsQueue<D> {
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        self.0.push_back(value)
+    }
+
+    fn remove(&mut self) -> Result<T, Error> {
+        self.0.pop_front()
+    }
+
+    fn peek(&self) -> Result<&T, Error> {
+        self.0.peek_front()
+    }
+
+    fn size(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Creates a new `Deque<T>`
+///
+/// Values are added to the back of the deque from left to right, therefore
+/// the first element in the list of parameters passed to the macro is at
+/// the front of the deque.
+///
+/// # Example
+/// ```
+/// # #[macro_use]
+/// # extern crate algorithms;
+/// # use crate::algorithms::data_structures::deque::*;
+///
+/// # fn main() {
+/// let d = deque![3, 4, 5];
+/// assert_eq!(d.peek_front().unwrap(), &3);
+/// assert_eq!(d.peek_back().unwrap(), &5);
+///
+/// let d_empty: Deque<isize> = deque![];
+/// assert_eq!(d_empty.len(), 0);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! deque {
+    () => { Deque::new() };
+    ($($x:expr),+) => {
+        {
+            let mut temp_d = Deque::default();
+            $(
+                let _ = temp_d.push_back($x);
+            )*
+            temp_d
+        }
+    };
+}
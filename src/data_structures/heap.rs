@@ -1,4 +1,17 @@
 use std::cmp::PartialOrd;
+use std::fmt;
+
+use crate::error::Error;
+
+pub mod bounded;
+pub mod concurrent;
+pub mod min_max;
+pub mod monotone;
+
+pub use bounded::BoundedHeap;
+pub use concurrent::{IsConcurrentPriorityQueue, LockedPriorityQueue, MultiQueue};
+pub use min_max::MinMaxHeap;
+pub use monotone::{BucketQueue, IsMonotoneHeap, RadixHeap};
 
 /// Represents a binary heap data structure.
 ///
@@ -21,6 +34,7 @@ use std::cmp::PartialOrd;
 /// let existing_data = vec![4, 10, 3, 5, 1];
 /// let max_heap = BinaryHeap::from_data_max(existing_data);
 /// ```
+#[derive(Debug, Clone)]
 pub enum BinaryHeap<T> 
 where
     T: PartialOrd
@@ -50,6 +64,7 @@ where
 /// let existing_data = vec![4, 10, 3, 5, 1];
 /// let min_heap = MinHeap::from_data(existing_data);
 /// ```
+#[derive(Debug, Clone)]
 pub struct MinHeap<T>
 where 
     T: PartialOrd
@@ -78,6 +93,7 @@ where
 /// let existing_data = vec![4, 10, 3, 5, 1];
 /// let max_heap = MaxHeap::from_data(existing_data);
 /// ```
+#[derive(Debug, Clone)]
 pub struct MaxHeap<T>
 where 
     T: PartialOrd
@@ -170,9 +186,121 @@ where
             BinaryHeap::Max(heap) => heap.data.len(),
         }
     }
+
+    /// Returns `true` if the binary heap contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BinaryHeap;
+    /// let min_heap: BinaryHeap<i32> = BinaryHeap::new_min();
+    /// assert!(min_heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds a new value to the binary heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BinaryHeap;
+    /// let mut max_heap: BinaryHeap<i32> = BinaryHeap::new_max();
+    /// max_heap.push(4);
+    /// max_heap.push(10);
+    /// assert_eq!(max_heap.len(), 2);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        match self {
+            BinaryHeap::Min(heap) => heap.push(value),
+            BinaryHeap::Max(heap) => heap.push(value),
+        }
+    }
+
+    /// Returns a reference to the root of the binary heap, which is the smallest
+    /// value for a MinHeap and the largest value for a MaxHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BinaryHeap;
+    /// let max_heap = BinaryHeap::from_data_max(vec![4, 10, 3]);
+    /// assert_eq!(max_heap.peek(), Some(&10));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        match self {
+            BinaryHeap::Min(heap) => heap.peek(),
+            BinaryHeap::Max(heap) => heap.peek(),
+        }
+    }
+
+    /// Removes the root of the binary heap and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BinaryHeap;
+    /// let mut min_heap = BinaryHeap::from_data_min(vec![4, 10, 3]);
+    /// assert_eq!(min_heap.pop(), Some(3));
+    /// assert_eq!(min_heap.pop(), Some(4));
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        match self {
+            BinaryHeap::Min(heap) => heap.pop(),
+            BinaryHeap::Max(heap) => heap.pop(),
+        }
+    }
+
+    /// Returns an iterator visiting all values in the binary heap, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BinaryHeap;
+    /// let min_heap = BinaryHeap::from_data_min(vec![4, 10, 3]);
+    /// assert_eq!(min_heap.iter().sum::<i32>(), 17);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        match self {
+            BinaryHeap::Min(heap) => heap.iter(),
+            BinaryHeap::Max(heap) => heap.iter(),
+        }
+    }
+
+    /// Checks that the binary heap satisfies its heap property.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If every parent is ordered correctly relative to its children
+    /// - `Err(Error::HeapViolation)`: The first parent/child pair (in index order)
+    ///   that breaks the heap property
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BinaryHeap;
+    /// let max_heap = BinaryHeap::from_data_max(vec![4, 10, 3, 5, 1]);
+    /// assert!(max_heap.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        match self {
+            BinaryHeap::Min(heap) => heap.validate(),
+            BinaryHeap::Max(heap) => heap.validate(),
+        }
+    }
 }
 
 
+impl<T> Default for MinHeap<T>
+where
+    T: PartialOrd
+{
+    /// Creates a new empty MinHeap.
+    fn default() -> Self {
+        MinHeap::new()
+    }
+}
+
 impl<T> MinHeap<T> 
 where
     T: PartialOrd
@@ -209,6 +337,146 @@ where
         new_heap
     }
 
+    /// Returns the number of elements in the MinHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let heap = MinHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the MinHeap contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let heap: MinHeap<i32> = MinHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Adds a new value to the MinHeap.
+    ///
+    /// The value is appended to the end of the heap and swapped with its
+    /// parent until the min-heap property is restored. Runs in O(lg *n*).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let mut heap = MinHeap::new();
+    /// heap.push(4);
+    /// heap.push(10);
+    /// heap.push(3);
+    /// assert!(heap.validate().is_ok());
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+
+        let mut i = self.data.len() - 1;
+        while i > 0 {
+            let p = parent(&i);
+            if self.data[i] < self.data[p] {
+                self.data.swap(i, p);
+                i = p;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Returns a reference to the smallest value in the MinHeap.
+    ///
+    /// # Returns
+    /// - `Some(&T)`: The root of the heap
+    /// - `None`: If the heap is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let heap = MinHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.peek(), Some(&3));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Removes the smallest value from the MinHeap and returns it.
+    ///
+    /// The last value in the heap replaces the root, which is then moved down
+    /// the tree to restore the min-heap property. Runs in O(lg *n*).
+    ///
+    /// # Returns
+    /// - `Some(T)`: The root of the heap
+    /// - `None`: If the heap is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let mut heap = MinHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), Some(4));
+    /// assert_eq!(heap.pop(), Some(10));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let value = self.data.swap_remove(0);
+        self.min_heapify(&0);
+        Some(value)
+    }
+
+    /// Returns an iterator visiting all values in the MinHeap, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let heap = MinHeap::from_data(vec![4, 10, 3]);
+    /// let mut values: Vec<&i32> = heap.iter().collect();
+    /// values.sort();
+    /// assert_eq!(values, [&3, &4, &10]);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Checks that the MinHeap satisfies the min-heap property.
+    ///
+    /// Every parent must be smaller than or equal to each of its children.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the min-heap property holds
+    /// - `Err(Error::HeapViolation)`: The first parent/child pair (in index order)
+    ///   that breaks the min-heap property
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let heap = MinHeap::from_data(vec![4, 10, 3, 5, 1]);
+    /// assert!(heap.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        match (1..self.data.len()).find(|i| self.data[*i] < self.data[parent(i)]) {
+            Some(child) => Err(Error::HeapViolation { parent: parent(&child), child }),
+            None => Ok(()),
+        }
+    }
+
     /// Organizes the data into a min-heap.
     ///
     /// This function takes the data in the `Vec<T>` and organizes it into a min-heap,
@@ -251,6 +519,16 @@ where
 }
 
 
+impl<T> Default for MaxHeap<T>
+where
+    T: PartialOrd
+{
+    /// Creates a new empty MaxHeap.
+    fn default() -> Self {
+        MaxHeap::new()
+    }
+}
+
 impl<T> MaxHeap<T> 
 where
     T: PartialOrd
@@ -287,6 +565,146 @@ where
         new_heap
     }
 
+    /// Returns the number of elements in the MaxHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let heap = MaxHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the MaxHeap contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let heap: MaxHeap<i32> = MaxHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Adds a new value to the MaxHeap.
+    ///
+    /// The value is appended to the end of the heap and swapped with its
+    /// parent until the max-heap property is restored. Runs in O(lg *n*).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let mut heap = MaxHeap::new();
+    /// heap.push(4);
+    /// heap.push(10);
+    /// heap.push(3);
+    /// assert!(heap.validate().is_ok());
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+
+        let mut i = self.data.len() - 1;
+        while i > 0 {
+            let p = parent(&i);
+            if self.data[i] > self.data[p] {
+                self.data.swap(i, p);
+                i = p;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Returns a reference to the largest value in the MaxHeap.
+    ///
+    /// # Returns
+    /// - `Some(&T)`: The root of the heap
+    /// - `None`: If the heap is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let heap = MaxHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.peek(), Some(&10));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Removes the largest value from the MaxHeap and returns it.
+    ///
+    /// The last value in the heap replaces the root, which is then moved down
+    /// the tree to restore the max-heap property. Runs in O(lg *n*).
+    ///
+    /// # Returns
+    /// - `Some(T)`: The root of the heap
+    /// - `None`: If the heap is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let mut heap = MaxHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.pop(), Some(10));
+    /// assert_eq!(heap.pop(), Some(4));
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let value = self.data.swap_remove(0);
+        self.max_heapify(&0);
+        Some(value)
+    }
+
+    /// Returns an iterator visiting all values in the MaxHeap, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let heap = MaxHeap::from_data(vec![4, 10, 3]);
+    /// let mut values: Vec<&i32> = heap.iter().collect();
+    /// values.sort();
+    /// assert_eq!(values, [&3, &4, &10]);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Checks that the MaxHeap satisfies the max-heap property.
+    ///
+    /// Every parent must be larger than or equal to each of its children.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the max-heap property holds
+    /// - `Err(Error::HeapViolation)`: The first parent/child pair (in index order)
+    ///   that breaks the max-heap property
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let heap = MaxHeap::from_data(vec![4, 10, 3, 5, 1]);
+    /// assert!(heap.validate().is_ok());
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        match (1..self.data.len()).find(|i| self.data[*i] > self.data[parent(i)]) {
+            Some(child) => Err(Error::HeapViolation { parent: parent(&child), child }),
+            None => Ok(()),
+        }
+    }
+
     /// Organizes the data into a max-heap.
     ///
     /// This function takes the data in the `Vec<T>` and organizes it into a max-heap,
@@ -328,11 +746,266 @@ where
     }
 }
 
+impl<T> Extend<T> for BinaryHeap<T>
+where
+    T: PartialOrd
+{
+    /// Pushes every value from an iterator onto the binary heap.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        match self {
+            BinaryHeap::Min(heap) => heap.extend(iter),
+            BinaryHeap::Max(heap) => heap.extend(iter),
+        }
+    }
+}
+
+impl<T> IntoIterator for BinaryHeap<T>
+where
+    T: PartialOrd
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consumes the binary heap, returning its values in arbitrary order.
+    fn into_iter(self) -> Self::IntoIter {
+        match self {
+            BinaryHeap::Min(heap) => heap.into_iter(),
+            BinaryHeap::Max(heap) => heap.into_iter(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BinaryHeap<T>
+where
+    T: PartialOrd
+{
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> fmt::Display for BinaryHeap<T>
+where
+    T: PartialOrd + fmt::Display
+{
+    /// Renders the binary heap as a tree, one node per line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BinaryHeap;
+    /// let max_heap = BinaryHeap::from_data_max(vec![1, 2, 3]);
+    /// assert_eq!(max_heap.to_string(), "3\n├── 2\n└── 1\n");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryHeap::Min(heap) => heap.fmt(f),
+            BinaryHeap::Max(heap) => heap.fmt(f),
+        }
+    }
+}
+
+impl<T> FromIterator<T> for MinHeap<T>
+where
+    T: PartialOrd
+{
+    /// Builds a MinHeap from an iterator in O(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let heap: MinHeap<i32> = vec![4, 10, 3].into_iter().collect();
+    /// assert_eq!(heap.len(), 3);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        MinHeap::from_data(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for MinHeap<T>
+where
+    T: PartialOrd
+{
+    /// Pushes every value from an iterator onto the MinHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let mut heap = MinHeap::from_data(vec![4, 10]);
+    /// heap.extend(vec![3, 5, 1]);
+    /// assert_eq!(heap.len(), 5);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.data.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for MinHeap<T>
+where
+    T: PartialOrd
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
 
+    /// Consumes the MinHeap, returning its values in arbitrary order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
 
+impl<'a, T> IntoIterator for &'a MinHeap<T>
+where
+    T: PartialOrd
+{
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
 
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> fmt::Display for MinHeap<T>
+where
+    T: PartialOrd + fmt::Display
+{
+    /// Renders the MinHeap as a tree, one node per line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinHeap;
+    /// let heap = MinHeap::from_data(vec![2, 2, 2, 2]);
+    /// assert_eq!(heap.to_string(), "2\n├── 2\n│   └── 2\n└── 2\n");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_tree(&self.data, f)
+    }
+}
+
+impl<T> FromIterator<T> for MaxHeap<T>
+where
+    T: PartialOrd
+{
+    /// Builds a MaxHeap from an iterator in O(*n*) time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let heap: MaxHeap<i32> = vec![4, 10, 3].into_iter().collect();
+    /// assert_eq!(heap.len(), 3);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        MaxHeap::from_data(iter.into_iter().collect())
+    }
+}
+
+impl<T> Extend<T> for MaxHeap<T>
+where
+    T: PartialOrd
+{
+    /// Pushes every value from an iterator onto the MaxHeap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let mut heap = MaxHeap::from_data(vec![4, 10]);
+    /// heap.extend(vec![3, 5, 1]);
+    /// assert_eq!(heap.len(), 5);
+    /// ```
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.data.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for MaxHeap<T>
+where
+    T: PartialOrd
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consumes the MaxHeap, returning its values in arbitrary order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a MaxHeap<T>
+where
+    T: PartialOrd
+{
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> fmt::Display for MaxHeap<T>
+where
+    T: PartialOrd + fmt::Display
+{
+    /// Renders the MaxHeap as a tree, one node per line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MaxHeap;
+    /// let heap = MaxHeap::from_data(vec![2, 2, 2, 2]);
+    /// assert_eq!(heap.to_string(), "2\n├── 2\n│   └── 2\n└── 2\n");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_tree(&self.data, f)
+    }
+}
+
+/// Writes the heap stored in `data` as a tree, with the root on the first line
+/// and each child indented below its parent. Left children are drawn first.
+fn fmt_tree<T: fmt::Display>(data: &[T], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if data.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(f, "{}", data[0])?;
+    fmt_subtree(data, 0, "", f)
+}
+
+/// Writes the children of node `i`, prefixing each line with `prefix`.
+fn fmt_subtree<T: fmt::Display>(data: &[T], i: usize, prefix: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let children: Vec<usize> = [left(&i), right(&i)]
+        .into_iter()
+        .filter(|c| *c < data.len())
+        .collect();
+
+    for (n, child) in children.iter().enumerate() {
+        let last = n == children.len() - 1;
+        let (branch, indent) = if last { ("└── ", "    ") } else { ("├── ", "│   ") };
+
+        writeln!(f, "{}{}{}", prefix, branch, data[*child])?;
+        fmt_subtree(data, *child, &format!("{}{}", prefix, indent), f)?;
+    }
+
+    Ok(())
+}
 
-fn _parent(i: &usize) -> usize {
+fn parent(i: &usize) -> usize {
     (i - 1) / 2
 }
 
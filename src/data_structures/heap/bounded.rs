@@ -0,0 +1,301 @@
+use std::cmp::PartialOrd;
+
+use super::{MaxHeap, MinHeap};
+
+/// A fixed-capacity heap that keeps only the best `capacity` values pushed into it.
+///
+/// A `BoundedHeap` either keeps the *largest* values (built on a `MinHeap`, so
+/// the worst kept value is always at the root) or the *smallest* values (built
+/// on a `MaxHeap`). Once the heap is full, pushing a better value evicts the
+/// current worst value and returns it, while pushing a value that is not
+/// better returns the value itself.
+///
+/// Pushing runs in O(lg *k*) for a capacity of *k*.
+///
+/// # Examples
+///
+/// Keeping the three highest scores on a leaderboard:
+/// ```
+/// # use crate::algorithms::data_structures::heap::BoundedHeap;
+/// let mut top = BoundedHeap::largest(3);
+///
+/// assert_eq!(top.push(50), None);
+/// assert_eq!(top.push(20), None);
+/// assert_eq!(top.push(70), None);
+///
+/// // 90 beats the current worst (20), which is evicted
+/// assert_eq!(top.push(90), Some(20));
+///
+/// // 10 is not good enough and is handed straight back
+/// assert_eq!(top.push(10), Some(10));
+///
+/// assert_eq!(top.into_sorted_vec(), vec![90, 70, 50]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedHeap<T>
+where
+    T: PartialOrd
+{
+    capacity: usize,
+    heap: Bounded<T>,
+}
+
+/// The underlying heap, whose root is always the worst value kept.
+#[derive(Debug, Clone)]
+enum Bounded<T>
+where
+    T: PartialOrd
+{
+    Largest(MinHeap<T>),
+    Smallest(MaxHeap<T>),
+}
+
+impl<T> BoundedHeap<T>
+where
+    T: PartialOrd
+{
+    /// Creates an empty `BoundedHeap` that keeps the `capacity` largest values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BoundedHeap;
+    /// let top: BoundedHeap<i32> = BoundedHeap::largest(10);
+    /// assert_eq!(top.capacity(), 10);
+    /// ```
+    pub fn largest(capacity: usize) -> Self {
+        BoundedHeap { capacity, heap: Bounded::Largest(MinHeap::new()) }
+    }
+
+    /// Creates an empty `BoundedHeap` that keeps the `capacity` smallest values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BoundedHeap;
+    /// let mut nearest = BoundedHeap::smallest(2);
+    /// nearest.push(3.5);
+    /// nearest.push(0.5);
+    /// nearest.push(1.5);
+    ///
+    /// assert_eq!(nearest.into_sorted_vec(), vec![0.5, 1.5]);
+    /// ```
+    pub fn smallest(capacity: usize) -> Self {
+        BoundedHeap { capacity, heap: Bounded::Smallest(MaxHeap::new()) }
+    }
+
+    /// Returns the maximum number of values the heap will keep.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of values currently kept in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BoundedHeap;
+    /// let mut top = BoundedHeap::largest(2);
+    /// top.push(1);
+    /// assert_eq!(top.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        match &self.heap {
+            Bounded::Largest(heap) => heap.len(),
+            Bounded::Smallest(heap) => heap.len(),
+        }
+    }
+
+    /// Returns `true` if the heap contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the heap holds `capacity` values, meaning further
+    /// pushes will evict a value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BoundedHeap;
+    /// let mut top = BoundedHeap::largest(1);
+    /// assert!(!top.is_full());
+    /// top.push(1);
+    /// assert!(top.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.len() >= self.capacity
+    }
+
+    /// Returns a reference to the worst value kept, which is the next
+    /// value to be evicted.
+    ///
+    /// Once the heap is full, this is the threshold a new value must beat
+    /// in order to be kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BoundedHeap;
+    /// let mut top = BoundedHeap::largest(2);
+    /// top.push(5);
+    /// top.push(8);
+    /// assert_eq!(top.peek_worst(), Some(&5));
+    /// ```
+    pub fn peek_worst(&self) -> Option<&T> {
+        match &self.heap {
+            Bounded::Largest(heap) => heap.peek(),
+            Bounded::Smallest(heap) => heap.peek(),
+        }
+    }
+
+    /// Offers a value to the heap.
+    ///
+    /// # Returns
+    /// - `None`: The value was kept and nothing was evicted
+    /// - `Some(T)`: The value that no longer fits. This is the previous worst
+    ///   value if `value` beat it, otherwise `value` itself
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BoundedHeap;
+    /// let mut nearest = BoundedHeap::smallest(1);
+    /// assert_eq!(nearest.push(4), None);
+    /// assert_eq!(nearest.push(2), Some(4));
+    /// assert_eq!(nearest.push(9), Some(9));
+    /// ```
+    pub fn push(&mut self, value: T) -> Option<T> {
+        if !self.is_full() {
+            match &mut self.heap {
+                Bounded::Largest(heap) => heap.push(value),
+                Bounded::Smallest(heap) => heap.push(value),
+            }
+            return None;
+        }
+
+        let better = match (&self.heap, self.peek_worst()) {
+            (Bounded::Largest(_), Some(worst)) => value > *worst,
+            (Bounded::Smallest(_), Some(worst)) => value < *worst,
+            (_, None) => false,
+        };
+
+        if !better {
+            return Some(value);
+        }
+
+        match &mut self.heap {
+            Bounded::Largest(heap) => {
+                let evicted = heap.pop();
+                heap.push(value);
+                evicted
+            }
+            Bounded::Smallest(heap) => {
+                let evicted = heap.pop();
+                heap.push(value);
+                evicted
+            }
+        }
+    }
+
+    /// Merges another bounded heap into this one.
+    ///
+    /// Every value in `other` is offered to this heap, so the result holds
+    /// the best `capacity` values seen by either heap. Values that do not
+    /// fit are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BoundedHeap;
+    /// let mut a = BoundedHeap::largest(3);
+    /// let mut b = BoundedHeap::largest(3);
+    /// for score in [1, 5, 9] { a.push(score); }
+    /// for score in [2, 6, 8] { b.push(score); }
+    ///
+    /// a.merge(b);
+    /// assert_eq!(a.into_sorted_vec(), vec![9, 8, 6]);
+    /// ```
+    pub fn merge(&mut self, other: BoundedHeap<T>) {
+        for value in other {
+            self.push(value);
+        }
+    }
+
+    /// Returns an iterator visiting all values in the heap, in arbitrary order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        match &self.heap {
+            Bounded::Largest(heap) => heap.iter(),
+            Bounded::Smallest(heap) => heap.iter(),
+        }
+    }
+
+    /// Consumes the heap and returns its values ordered best first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::BoundedHeap;
+    /// let mut top = BoundedHeap::largest(3);
+    /// for score in [4, 10, 3, 5, 1] { top.push(score); }
+    ///
+    /// assert_eq!(top.into_sorted_vec(), vec![10, 5, 4]);
+    /// ```
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        let mut sorted = Vec::with_capacity(self.len());
+        match self.heap {
+            Bounded::Largest(mut heap) => {
+                while let Some(value) = heap.pop() {
+                    sorted.push(value);
+                }
+            }
+            Bounded::Smallest(mut heap) => {
+                while let Some(value) = heap.pop() {
+                    sorted.push(value);
+                }
+            }
+        }
+        sorted.reverse();
+        sorted
+    }
+}
+
+impl<T> Extend<T> for BoundedHeap<T>
+where
+    T: PartialOrd
+{
+    /// Offers every value from an iterator to the heap, dropping any evictions.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for BoundedHeap<T>
+where
+    T: PartialOrd
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Consumes the heap, returning its values in arbitrary order.
+    fn into_iter(self) -> Self::IntoIter {
+        match self.heap {
+            Bounded::Largest(heap) => heap.into_iter(),
+            Bounded::Smallest(heap) => heap.into_iter(),
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BoundedHeap<T>
+where
+    T: PartialOrd
+{
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
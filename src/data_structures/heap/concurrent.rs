@@ -0,0 +1,422 @@
+use std::cmp::PartialOrd;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rand::{thread_rng, Rng};
+
+use super::BinaryHeap;
+
+/// Defines methods expected on a priority queue shared between threads.
+///
+/// All methods take `&self`, so a queue can be wrapped in an `Arc` and
+/// shared by any number of producer and consumer threads.
+pub trait IsConcurrentPriorityQueue<T> {
+    /// Adds a new value to the queue, waking a waiting consumer if there is one.
+    fn push(&self, value: T);
+
+    /// Removes the highest priority value from the queue without blocking.
+    ///
+    /// # Returns
+    /// - `Some(T)`: The highest priority value
+    /// - `None`: If the queue is empty
+    fn try_pop(&self) -> Option<T>;
+
+    /// Removes the highest priority value from the queue, blocking the
+    /// current thread until a value is available.
+    fn pop_wait(&self) -> T;
+
+    /// Removes the highest priority value from the queue, blocking the
+    /// current thread for at most `timeout`.
+    ///
+    /// # Returns
+    /// - `Some(T)`: The highest priority value
+    /// - `None`: If no value became available before the timeout elapsed
+    fn pop_timeout(&self, timeout: Duration) -> Option<T>;
+
+    /// Gets the number of values in the queue.
+    ///
+    /// Other threads may push or pop at any time, so the result is only a
+    /// snapshot.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the queue contained no values when checked.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A thread-safe priority queue protected by a single lock.
+///
+/// Every operation takes the same `Mutex` around a `BinaryHeap`, so values
+/// are always popped in strict priority order. This is the simplest correct
+/// baseline, but all threads contend on one lock. See `MultiQueue` for a
+/// sharded alternative.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use std::thread;
+/// # use crate::algorithms::data_structures::heap::{IsConcurrentPriorityQueue, LockedPriorityQueue};
+/// let queue = Arc::new(LockedPriorityQueue::new_max());
+///
+/// let producer = {
+///     let queue = Arc::clone(&queue);
+///     thread::spawn(move || {
+///         for job in 0..10 {
+///             queue.push(job);
+///         }
+///     })
+/// };
+/// producer.join().unwrap();
+///
+/// assert_eq!(queue.pop_wait(), 9);
+/// assert_eq!(queue.try_pop(), Some(8));
+/// assert_eq!(queue.len(), 8);
+/// ```
+#[derive(Debug)]
+pub struct LockedPriorityQueue<T>
+where
+    T: PartialOrd
+{
+    heap: Mutex<BinaryHeap<T>>,
+    available: Condvar,
+}
+
+impl<T> LockedPriorityQueue<T>
+where
+    T: PartialOrd
+{
+    /// Creates an empty queue that pops the smallest value first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::{IsConcurrentPriorityQueue, LockedPriorityQueue};
+    /// let queue = LockedPriorityQueue::new_min();
+    /// queue.push(4);
+    /// queue.push(1);
+    /// assert_eq!(queue.try_pop(), Some(1));
+    /// ```
+    pub fn new_min() -> Self {
+        LockedPriorityQueue::from_heap(BinaryHeap::new_min())
+    }
+
+    /// Creates an empty queue that pops the largest value first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::{IsConcurrentPriorityQueue, LockedPriorityQueue};
+    /// let queue = LockedPriorityQueue::new_max();
+    /// queue.push(4);
+    /// queue.push(1);
+    /// assert_eq!(queue.try_pop(), Some(4));
+    /// ```
+    pub fn new_max() -> Self {
+        LockedPriorityQueue::from_heap(BinaryHeap::new_max())
+    }
+
+    /// Creates a queue holding the values of an existing heap.
+    pub fn from_heap(heap: BinaryHeap<T>) -> Self {
+        LockedPriorityQueue { heap: Mutex::new(heap), available: Condvar::new() }
+    }
+
+    /// Consumes the queue, returning the underlying heap.
+    pub fn into_inner(self) -> BinaryHeap<T> {
+        self.heap.into_inner().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BinaryHeap<T>> {
+        self.heap.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl<T> IsConcurrentPriorityQueue<T> for LockedPriorityQueue<T>
+where
+    T: PartialOrd
+{
+    fn push(&self, value: T) {
+        self.lock().push(value);
+        self.available.notify_one();
+    }
+
+    fn try_pop(&self) -> Option<T> {
+        self.lock().pop()
+    }
+
+    fn pop_wait(&self) -> T {
+        let mut heap = self.lock();
+        loop {
+            if let Some(value) = heap.pop() {
+                return value;
+            }
+            heap = self.available.wait(heap).unwrap_or_else(|e| e.into_inner());
+        }
+    }
+
+    fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        let mut heap = self.lock();
+        loop {
+            if let Some(value) = heap.pop() {
+                return Some(value);
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return None;
+            }
+            heap = self.available
+                .wait_timeout(heap, deadline - now)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.lock().len()
+    }
+}
+
+/// A relaxed, sharded priority queue (a *MultiQueue*).
+///
+/// Values are spread over several independently locked `BinaryHeap` shards.
+/// `push` inserts into a random uncontended shard, and `try_pop` compares the
+/// roots of two random shards and pops the better one. Threads rarely contend
+/// on the same lock, at the cost of popping values in only *approximately*
+/// priority order: the popped value is not necessarily the very best in the
+/// queue. Two-choice sampling keeps its rank small *in expectation* (on the
+/// order of the number of shards), but there is no worst-case bound.
+///
+/// When neither sampled shard has a value, every shard is checked in turn, so
+/// `try_pop` does not give up while values are sitting in other shards.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use std::thread;
+/// # use crate::algorithms::data_structures::heap::{IsConcurrentPriorityQueue, MultiQueue};
+/// let queue = Arc::new(MultiQueue::new_min(4));
+///
+/// let producers: Vec<_> = (0..4)
+///     .map(|t| {
+///         let queue = Arc::clone(&queue);
+///         thread::spawn(move || {
+///             for job in 0..25 {
+///                 queue.push(t * 25 + job);
+///             }
+///         })
+///     })
+///     .collect();
+/// for producer in producers {
+///     producer.join().unwrap();
+/// }
+///
+/// let mut jobs = Vec::new();
+/// while let Some(job) = queue.try_pop() {
+///     jobs.push(job);
+/// }
+/// jobs.sort();
+/// assert_eq!(jobs, (0..100).collect::<Vec<_>>());
+/// ```
+#[derive(Debug)]
+pub struct MultiQueue<T>
+where
+    T: PartialOrd
+{
+    shards: Vec<Mutex<BinaryHeap<T>>>,
+    len: AtomicUsize,
+    min: bool,
+    sleep: Mutex<()>,
+    available: Condvar,
+}
+
+impl<T> MultiQueue<T>
+where
+    T: PartialOrd
+{
+    /// Creates an empty queue with `shards` shards that pops small values first.
+    ///
+    /// A good choice for `shards` is two to four times the number of threads
+    /// sharing the queue.
+    ///
+    /// # Panics
+    /// Panics if `shards` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::{IsConcurrentPriorityQueue, MultiQueue};
+    /// let queue = MultiQueue::new_min(1);
+    /// queue.push(4);
+    /// queue.push(1);
+    /// assert_eq!(queue.try_pop(), Some(1));
+    /// ```
+    pub fn new_min(shards: usize) -> Self {
+        MultiQueue::with_shards(shards, true)
+    }
+
+    /// Creates an empty queue with `shards` shards that pops large values first.
+    ///
+    /// # Panics
+    /// Panics if `shards` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::{IsConcurrentPriorityQueue, MultiQueue};
+    /// let queue = MultiQueue::new_max(1);
+    /// queue.push(4);
+    /// queue.push(1);
+    /// assert_eq!(queue.try_pop(), Some(4));
+    /// ```
+    pub fn new_max(shards: usize) -> Self {
+        MultiQueue::with_shards(shards, false)
+    }
+
+    /// Gets the number of shards the queue is split into.
+    pub fn shards(&self) -> usize {
+        self.shards.len()
+    }
+
+    fn with_shards(shards: usize, min: bool) -> Self {
+        assert!(shards > 0);
+
+        let new_heap = || if min { BinaryHeap::new_min() } else { BinaryHeap::new_max() };
+        MultiQueue {
+            shards: (0..shards).map(|_| Mutex::new(new_heap())).collect(),
+            len: AtomicUsize::new(0),
+            min,
+            sleep: Mutex::new(()),
+            available: Condvar::new(),
+        }
+    }
+
+    /// Returns `true` if `a` should be popped before `b`.
+    fn before(&self, a: &T, b: &T) -> bool {
+        if self.min { a < b } else { a > b }
+    }
+
+    /// Pops from the better of two randomly chosen shards, if either is
+    /// available and non-empty.
+    fn pop_sampled(&self) -> Option<T> {
+        let mut rng = thread_rng();
+        let i = rng.gen_range(0..self.shards.len());
+        let j = rng.gen_range(0..self.shards.len());
+
+        let mut a = self.shards[i].try_lock().ok()?;
+        let b = if i != j { self.shards[j].try_lock().ok() } else { None };
+
+        let use_b = match (a.peek(), b.as_ref().and_then(|b| b.peek())) {
+            (Some(x), Some(y)) => self.before(y, x),
+            (None, Some(_)) => true,
+            _ => false,
+        };
+
+        if use_b { b?.pop() } else { a.pop() }
+    }
+
+    /// Pops from the first non-empty shard, waiting on each lock in turn.
+    fn pop_scan(&self) -> Option<T> {
+        self.shards
+            .iter()
+            .find_map(|shard| shard.lock().unwrap_or_else(|e| e.into_inner()).pop())
+    }
+}
+
+impl<T> IsConcurrentPriorityQueue<T> for MultiQueue<T>
+where
+    T: PartialOrd
+{
+    fn push(&self, value: T) {
+        // Count the value before it becomes visible so that `len` never
+        // drops below the number of values a consumer can actually pop.
+        self.len.fetch_add(1, Ordering::SeqCst);
+
+        let mut rng = thread_rng();
+        let mut value = Some(value);
+        while let Some(v) = value.take() {
+            let i = rng.gen_range(0..self.shards.len());
+            match self.shards[i].try_lock() {
+                Ok(mut shard) => shard.push(v),
+                Err(_) => value = Some(v),
+            }
+        }
+
+        // Take the sleep lock before notifying so a consumer that has just
+        // seen an empty queue cannot miss the wake-up.
+        let _guard = self.sleep.lock().unwrap_or_else(|e| e.into_inner());
+        self.available.notify_one();
+    }
+
+    fn try_pop(&self) -> Option<T> {
+        if self.len.load(Ordering::SeqCst) == 0 {
+            return None;
+        }
+
+        let value = (0..self.shards.len())
+            .find_map(|_| self.pop_sampled())
+            .or_else(|| self.pop_scan())?;
+
+        self.len.fetch_sub(1, Ordering::SeqCst);
+        Some(value)
+    }
+
+    fn pop_wait(&self) -> T {
+        loop {
+            if let Some(value) = self.try_pop() {
+                return value;
+            }
+
+            // A push counts its value before storing it, so `len` can be
+            // non-zero with nothing to pop yet. Let the pusher finish rather
+            // than spinning on `try_pop`.
+            if self.len.load(Ordering::SeqCst) > 0 {
+                thread::yield_now();
+                continue;
+            }
+
+            let mut guard = self.sleep.lock().unwrap_or_else(|e| e.into_inner());
+            while self.len.load(Ordering::SeqCst) == 0 {
+                guard = self.available.wait(guard).unwrap_or_else(|e| e.into_inner());
+            }
+        }
+    }
+
+    fn pop_timeout(&self, timeout: Duration) -> Option<T> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(value) = self.try_pop() {
+                return Some(value);
+            }
+
+            if self.len.load(Ordering::SeqCst) > 0 {
+                if Instant::now() >= deadline {
+                    return None;
+                }
+                thread::yield_now();
+                continue;
+            }
+
+            let mut guard = self.sleep.lock().unwrap_or_else(|e| e.into_inner());
+            while self.len.load(Ordering::SeqCst) == 0 {
+                let now = Instant::now();
+                if now >= deadline {
+                    return None;
+                }
+                guard = self.available
+                    .wait_timeout(guard, deadline - now)
+                    .unwrap_or_else(|e| e.into_inner())
+                    .0;
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len.load(Ordering::SeqCst)
+    }
+}
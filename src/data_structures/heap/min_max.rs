@@ -0,0 +1,354 @@
+use std::cmp::PartialOrd;
+
+use super::{left, parent, right};
+
+/// Represents a Min-Max Heap data structure (a double-ended priority queue).
+///
+/// A min-max heap is a complete binary tree stored in a `Vec<T>` where nodes
+/// on even levels (starting with the root at level 0) are smaller than or
+/// equal to all of their descendants, and nodes on odd levels are larger than
+/// or equal to all of their descendants. The smallest element is therefore
+/// always the root, and the largest element is one of the root's children.
+///
+/// Both extremes can be read in O(1) and removed in O(lg *n*).
+///
+/// # Examples
+///
+/// Creating a new empty MinMaxHeap:
+/// ```
+/// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+/// // Specify the type when creating an empty heap.
+/// let heap: MinMaxHeap<i32> = MinMaxHeap::new();
+/// ```
+///
+/// Creating a new MinMaxHeap from existing data:
+/// ```
+/// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+/// let existing_data = vec![4, 10, 3, 5, 1];
+/// let mut heap = MinMaxHeap::from_data(existing_data);
+///
+/// assert_eq!(heap.peek_min(), Some(&1));
+/// assert_eq!(heap.peek_max(), Some(&10));
+///
+/// assert_eq!(heap.pop_max(), Some(10));
+/// assert_eq!(heap.pop_min(), Some(1));
+/// assert_eq!(heap.len(), 3);
+/// ```
+pub struct MinMaxHeap<T>
+where
+    T: PartialOrd
+{
+    data: Vec<T>,
+}
+
+impl<T> Default for MinMaxHeap<T>
+where
+    T: PartialOrd
+{
+    /// Creates a new empty MinMaxHeap.
+    fn default() -> Self {
+        MinMaxHeap::new()
+    }
+}
+
+impl<T> MinMaxHeap<T>
+where
+    T: PartialOrd
+{
+    /// Creates a new empty MinMaxHeap.
+    ///
+    /// This function creates a new empty MinMaxHeap and returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let heap: MinMaxHeap<i32> = MinMaxHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        MinMaxHeap { data: Vec::new() }
+    }
+
+    /// Creates a new MinMaxHeap from an existing data vector.
+    ///
+    /// This function takes a `Vec<T>` as input and creates a new MinMaxHeap
+    /// from it in O(*n*) time. The min-max heap property is enforced during
+    /// the creation process.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let existing_data = vec![4, 10, 3, 5, 1];
+    /// let heap: MinMaxHeap<i32> = MinMaxHeap::from_data(existing_data);
+    /// assert_eq!(heap.len(), 5);
+    /// ```
+    pub fn from_data(data: Vec<T>) -> Self {
+        let mut new_heap = MinMaxHeap { data };
+        new_heap.build_heap();
+        new_heap
+    }
+
+    /// Returns the number of elements in the heap.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let heap = MinMaxHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if the heap contains no elements.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let heap: MinMaxHeap<i32> = MinMaxHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Adds a new value to the heap.
+    ///
+    /// The value is appended to the end of the heap and moved up the tree
+    /// until the min-max heap property is restored. Runs in O(lg *n*).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// heap.push(5);
+    /// heap.push(1);
+    /// heap.push(9);
+    ///
+    /// assert_eq!(heap.peek_min(), Some(&1));
+    /// assert_eq!(heap.peek_max(), Some(&9));
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.data.push(value);
+        self.bubble_up(self.data.len() - 1);
+    }
+
+    /// Returns a reference to the smallest value in the heap.
+    ///
+    /// # Returns
+    /// - `Some(&T)`: The smallest value in the heap
+    /// - `None`: If the heap is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let heap = MinMaxHeap::from_data(vec![4, 10, 3, 5, 1]);
+    /// assert_eq!(heap.peek_min(), Some(&1));
+    /// ```
+    pub fn peek_min(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns a reference to the largest value in the heap.
+    ///
+    /// # Returns
+    /// - `Some(&T)`: The largest value in the heap
+    /// - `None`: If the heap is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let heap = MinMaxHeap::from_data(vec![4, 10, 3, 5, 1]);
+    /// assert_eq!(heap.peek_max(), Some(&10));
+    /// ```
+    pub fn peek_max(&self) -> Option<&T> {
+        self.max_index().map(|i| &self.data[i])
+    }
+
+    /// Removes the smallest value from the heap and returns it.
+    ///
+    /// # Returns
+    /// - `Some(T)`: The smallest value in the heap
+    /// - `None`: If the heap is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.pop_min(), Some(3));
+    /// assert_eq!(heap.pop_min(), Some(4));
+    /// assert_eq!(heap.pop_min(), Some(10));
+    /// assert_eq!(heap.pop_min(), None);
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.remove_at(0)
+    }
+
+    /// Removes the largest value from the heap and returns it.
+    ///
+    /// # Returns
+    /// - `Some(T)`: The largest value in the heap
+    /// - `None`: If the heap is empty
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::from_data(vec![4, 10, 3]);
+    /// assert_eq!(heap.pop_max(), Some(10));
+    /// assert_eq!(heap.pop_max(), Some(4));
+    /// assert_eq!(heap.pop_max(), Some(3));
+    /// assert_eq!(heap.pop_max(), None);
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        let i = self.max_index()?;
+        self.remove_at(i)
+    }
+
+    /// Organizes the data into a min-max heap.
+    ///
+    /// Starting from the last non-leaf node and working back to the root,
+    /// each node is trickled down to its correct position. As with a regular
+    /// binary heap, this runs in O(*n*) time.
+    fn build_heap(&mut self) {
+        let heap_size = self.data.len();
+        for i in (0..(heap_size / 2)).rev() {
+            self.trickle_down(i);
+        }
+    }
+
+    /// Finds the index of the largest value, which is either the root (for a
+    /// heap with a single element) or one of the root's children.
+    fn max_index(&self) -> Option<usize> {
+        match self.data.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ => {
+                if self.data[2] > self.data[1] {
+                    Some(2)
+                } else {
+                    Some(1)
+                }
+            }
+        }
+    }
+
+    /// Removes the value at index `i`, replacing it with the last element
+    /// and restoring the min-max heap property below it.
+    fn remove_at(&mut self, i: usize) -> Option<T> {
+        if i >= self.data.len() {
+            return None;
+        }
+
+        let value = self.data.swap_remove(i);
+        if i < self.data.len() {
+            self.trickle_down(i);
+        }
+        Some(value)
+    }
+
+    /// Moves the value at index `i` up the tree until the min-max heap
+    /// property is satisfied.
+    fn bubble_up(&mut self, i: usize) {
+        if i == 0 {
+            return;
+        }
+
+        let p = parent(&i);
+        if is_min_level(i) {
+            if self.data[i] > self.data[p] {
+                self.data.swap(i, p);
+                self.bubble_up_by(p, |a, b| a > b);
+            } else {
+                self.bubble_up_by(i, |a, b| a < b);
+            }
+        } else if self.data[i] < self.data[p] {
+            self.data.swap(i, p);
+            self.bubble_up_by(p, |a, b| a < b);
+        } else {
+            self.bubble_up_by(i, |a, b| a > b);
+        }
+    }
+
+    /// Moves the value at index `i` up through its grandparents while
+    /// `before(value, grandparent)` holds.
+    fn bubble_up_by<F>(&mut self, mut i: usize, before: F)
+    where
+        F: Fn(&T, &T) -> bool
+    {
+        while i > 2 {
+            let grandparent = parent(&parent(&i));
+            if before(&self.data[i], &self.data[grandparent]) {
+                self.data.swap(i, grandparent);
+                i = grandparent;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Moves the value at index `i` down the tree until the min-max heap
+    /// property is satisfied.
+    fn trickle_down(&mut self, i: usize) {
+        if is_min_level(i) {
+            self.trickle_down_by(i, |a, b| a < b);
+        } else {
+            self.trickle_down_by(i, |a, b| a > b);
+        }
+    }
+
+    /// Moves the value at index `i` down through its children and
+    /// grandchildren, where `before(a, b)` is `true` if `a` should sit
+    /// above `b` on the current level type.
+    fn trickle_down_by<F>(&mut self, mut i: usize, before: F)
+    where
+        F: Fn(&T, &T) -> bool
+    {
+        loop {
+            let l = left(&i);
+            if l >= self.data.len() {
+                break;
+            }
+
+            // Find the most extreme of the children and grandchildren.
+            let mut m = l;
+            let candidates = [right(&i), left(&l), right(&l), left(&right(&i)), right(&right(&i))];
+            for c in candidates {
+                if c < self.data.len() && before(&self.data[c], &self.data[m]) {
+                    m = c;
+                }
+            }
+
+            if !before(&self.data[m], &self.data[i]) {
+                break;
+            }
+
+            self.data.swap(m, i);
+
+            // A child has no descendants on our level type, so we are done.
+            if m <= right(&i) {
+                break;
+            }
+
+            let p = parent(&m);
+            if before(&self.data[p], &self.data[m]) {
+                self.data.swap(m, p);
+            }
+            i = m;
+        }
+    }
+}
+
+/// Returns `true` if index `i` sits on a min level (an even depth) of the tree.
+fn is_min_level(i: usize) -> bool {
+    (i + 1).ilog2().is_multiple_of(2)
+}
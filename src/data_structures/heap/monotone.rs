@@ -0,0 +1,358 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::error::Error;
+
+/// Defines methods expected on a monotone priority queue.
+///
+/// A monotone priority queue pops values in non-decreasing priority order and
+/// requires that no value is ever pushed (or decreased) below the priority
+/// that was most recently popped. This is exactly the access pattern of
+/// Dijkstra's algorithm with non-negative integer edge weights, and it allows
+/// faster implementations than a general binary heap.
+///
+/// Values are used as keys, so each value may be queued at most once at a time.
+pub trait IsMonotoneHeap<T> {
+    /// Adds a new value with the given priority.
+    ///
+    /// # Errors
+    /// - `Error::PriorityOutOfRange`: If `priority` is below the last popped
+    ///   priority, or too far above it for the queue to hold
+    /// - `Error::Duplicate`: If `value` is already queued
+    fn push(&mut self, value: T, priority: usize) -> Result<(), Error>;
+
+    /// Removes the value with the smallest priority and returns it along
+    /// with its priority.
+    ///
+    /// # Returns
+    /// - `Some((T, usize))`: The value with the smallest priority
+    /// - `None`: If the queue is empty
+    fn pop(&mut self) -> Option<(T, usize)>;
+
+    /// Lowers the priority of a queued value.
+    ///
+    /// # Errors
+    /// - `Error::NotFound`: If `value` is not queued
+    /// - `Error::PriorityNotDecreased`: If `priority` is not lower than the
+    ///   current priority of `value`
+    /// - `Error::PriorityOutOfRange`: If `priority` is below the last popped
+    ///   priority
+    fn decrease_key(&mut self, value: &T, priority: usize) -> Result<(), Error>;
+
+    /// Gets the priority most recently popped, which is the lowest priority
+    /// that may still be pushed.
+    fn last(&self) -> usize;
+
+    /// Gets the number of values in the queue.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the queue contains no values.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A bucket queue for Dial's algorithm.
+///
+/// Values are stored in a circular array of `max_span + 1` buckets, one per
+/// priority, where `max_span` is the largest difference allowed between any
+/// queued priority and the last popped priority (for Dijkstra, the largest
+/// edge weight). `push` and `decrease_key` are O(1), and `pop` is O(`max_span`)
+/// in the worst case.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::heap::{BucketQueue, IsMonotoneHeap};
+/// # use crate::algorithms::error::Error;
+/// let mut queue = BucketQueue::new(10);
+/// queue.push('a', 7).unwrap();
+/// queue.push('b', 3).unwrap();
+/// queue.push('c', 9).unwrap();
+///
+/// queue.decrease_key(&'c', 5).unwrap();
+///
+/// assert_eq!(queue.pop(), Some(('b', 3)));
+/// assert_eq!(queue.pop(), Some(('c', 5)));
+///
+/// // Priorities below the last popped priority are rejected
+/// assert_eq!(
+///     queue.push('d', 4),
+///     Err(Error::PriorityOutOfRange { priority: 4, min: 5, max: 15 })
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct BucketQueue<T>
+where
+    T: Eq + Hash + Clone
+{
+    buckets: Vec<Vec<T>>,
+    priorities: HashMap<T, usize>,
+    last: usize,
+}
+
+impl<T> BucketQueue<T>
+where
+    T: Eq + Hash + Clone
+{
+    /// Creates an empty `BucketQueue` that accepts priorities up to `max_span`
+    /// above the last popped priority.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::{BucketQueue, IsMonotoneHeap};
+    /// let mut queue = BucketQueue::new(5);
+    /// assert!(queue.push(0, 5).is_ok());
+    /// assert!(queue.push(1, 6).is_err());
+    /// ```
+    pub fn new(max_span: usize) -> Self {
+        BucketQueue {
+            buckets: vec![Vec::new(); max_span + 1],
+            priorities: HashMap::new(),
+            last: 0,
+        }
+    }
+
+    /// Gets the largest difference allowed between a queued priority and the
+    /// last popped priority.
+    pub fn max_span(&self) -> usize {
+        self.buckets.len() - 1
+    }
+
+    /// Checks that `priority` lies within the window of priorities the
+    /// buckets can currently hold.
+    fn check_priority(&self, priority: usize) -> Result<(), Error> {
+        let max = self.last.saturating_add(self.max_span());
+        if priority < self.last || priority > max {
+            return Err(Error::PriorityOutOfRange { priority, min: self.last, max });
+        }
+        Ok(())
+    }
+
+    fn bucket(&self, priority: usize) -> usize {
+        priority % self.buckets.len()
+    }
+}
+
+impl<T> IsMonotoneHeap<T> for BucketQueue<T>
+where
+    T: Eq + Hash + Clone
+{
+    fn push(&mut self, value: T, priority: usize) -> Result<(), Error> {
+        self.check_priority(priority)?;
+        if self.priorities.contains_key(&value) {
+            return Err(Error::Duplicate);
+        }
+
+        let b = self.bucket(priority);
+        self.buckets[b].push(value.clone());
+        self.priorities.insert(value, priority);
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<(T, usize)> {
+        if self.priorities.is_empty() {
+            return None;
+        }
+
+        // Every queued priority lies within `max_span` of `last`, so the scan
+        // always finds a value. Entries left behind by `decrease_key` no longer
+        // match the recorded priority and are discarded along the way.
+        loop {
+            let b = self.bucket(self.last);
+            while let Some(value) = self.buckets[b].pop() {
+                if self.priorities.get(&value) == Some(&self.last) {
+                    self.priorities.remove(&value);
+                    return Some((value, self.last));
+                }
+            }
+            self.last += 1;
+        }
+    }
+
+    fn decrease_key(&mut self, value: &T, priority: usize) -> Result<(), Error> {
+        let current = match self.priorities.get(value) {
+            Some(current) => *current,
+            None => return Err(Error::NotFound),
+        };
+        if priority >= current {
+            return Err(Error::PriorityNotDecreased { current, priority });
+        }
+        self.check_priority(priority)?;
+
+        // The old entry is left in place and skipped when it is reached.
+        let b = self.bucket(priority);
+        self.buckets[b].push(value.clone());
+        self.priorities.insert(value.clone(), priority);
+        Ok(())
+    }
+
+    fn last(&self) -> usize {
+        self.last
+    }
+
+    fn len(&self) -> usize {
+        self.priorities.len()
+    }
+}
+
+/// A radix heap for monotone integer priorities.
+///
+/// Values are kept in `usize::BITS + 1` buckets, where a value with priority
+/// *p* is stored in the bucket given by the position of the highest bit in
+/// which *p* differs from the last popped priority. When the lowest bucket is
+/// empty, the next non-empty bucket is redistributed into lower buckets. Each
+/// value can only move down, so `pop` is amortised O(lg *C*) for priorities
+/// spanning a range of *C*, while `push` and `decrease_key` are O(1).
+///
+/// Unlike `BucketQueue`, there is no limit on how far above the last popped
+/// priority a value may be pushed.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::heap::{IsMonotoneHeap, RadixHeap};
+/// let mut heap = RadixHeap::new();
+/// heap.push("far", 1_000_000).unwrap();
+/// heap.push("near", 2).unwrap();
+/// heap.push("mid", 300).unwrap();
+///
+/// heap.decrease_key(&"far", 40).unwrap();
+///
+/// assert_eq!(heap.pop(), Some(("near", 2)));
+/// assert_eq!(heap.pop(), Some(("far", 40)));
+/// assert_eq!(heap.pop(), Some(("mid", 300)));
+/// assert_eq!(heap.pop(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RadixHeap<T>
+where
+    T: Eq + Hash + Clone
+{
+    buckets: Vec<Vec<(usize, T)>>,
+    priorities: HashMap<T, usize>,
+    last: usize,
+}
+
+impl<T> Default for RadixHeap<T>
+where
+    T: Eq + Hash + Clone
+{
+    /// Creates an empty `RadixHeap`.
+    fn default() -> Self {
+        RadixHeap::new()
+    }
+}
+
+impl<T> RadixHeap<T>
+where
+    T: Eq + Hash + Clone
+{
+    /// Creates an empty `RadixHeap`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use crate::algorithms::data_structures::heap::{IsMonotoneHeap, RadixHeap};
+    /// let heap: RadixHeap<usize> = RadixHeap::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        RadixHeap {
+            buckets: vec![Vec::new(); usize::BITS as usize + 1],
+            priorities: HashMap::new(),
+            last: 0,
+        }
+    }
+
+    fn check_priority(&self, priority: usize) -> Result<(), Error> {
+        if priority < self.last {
+            return Err(Error::PriorityOutOfRange { priority, min: self.last, max: usize::MAX });
+        }
+        Ok(())
+    }
+
+    /// Finds the bucket for `priority`, which must not be below `last`.
+    fn bucket(&self, priority: usize) -> usize {
+        (usize::BITS - (priority ^ self.last).leading_zeros()) as usize
+    }
+
+    fn is_live(&self, priority: usize, value: &T) -> bool {
+        self.priorities.get(value) == Some(&priority)
+    }
+}
+
+impl<T> IsMonotoneHeap<T> for RadixHeap<T>
+where
+    T: Eq + Hash + Clone
+{
+    fn push(&mut self, value: T, priority: usize) -> Result<(), Error> {
+        self.check_priority(priority)?;
+        if self.priorities.contains_key(&value) {
+            return Err(Error::Duplicate);
+        }
+
+        let b = self.bucket(priority);
+        self.buckets[b].push((priority, value.clone()));
+        self.priorities.insert(value, priority);
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<(T, usize)> {
+        loop {
+            if self.priorities.is_empty() {
+                return None;
+            }
+
+            while let Some((priority, value)) = self.buckets[0].pop() {
+                if self.is_live(priority, &value) {
+                    self.priorities.remove(&value);
+                    return Some((value, priority));
+                }
+            }
+
+            // Redistribute the first non-empty bucket around its smallest
+            // live priority, dropping entries left behind by `decrease_key`.
+            let i = self.buckets.iter().position(|b| !b.is_empty())?;
+            let entries: Vec<(usize, T)> = std::mem::take(&mut self.buckets[i])
+                .into_iter()
+                .filter(|(priority, value)| self.is_live(*priority, value))
+                .collect();
+
+            if let Some(min) = entries.iter().map(|(priority, _)| *priority).min() {
+                self.last = min;
+                for (priority, value) in entries {
+                    let b = self.bucket(priority);
+                    self.buckets[b].push((priority, value));
+                }
+            }
+        }
+    }
+
+    fn decrease_key(&mut self, value: &T, priority: usize) -> Result<(), Error> {
+        let current = match self.priorities.get(value) {
+            Some(current) => *current,
+            None => return Err(Error::NotFound),
+        };
+        if priority >= current {
+            return Err(Error::PriorityNotDecreased { current, priority });
+        }
+        self.check_priority(priority)?;
+
+        // The old entry is left in place and dropped when its bucket is
+        // next redistributed.
+        let b = self.bucket(priority);
+        self.buckets[b].push((priority, value.clone()));
+        self.priorities.insert(value.clone(), priority);
+        Ok(())
+    }
+
+    fn last(&self) -> usize {
+        self.last
+    }
+
+    fn len(&self) -> usize {
+        self.priorities.len()
+    }
+}
@@ -1,6 +1,30 @@
-use std::error::Error;
+use std::iter::{Skip, StepBy, Take};
+use std::marker::PhantomData;
+use std::slice;
+
 use num_traits::Num;
 
+use crate::error::Error;
+
+mod cholesky;
+mod eigen;
+mod index;
+mod lu;
+mod multiply;
+mod ops;
+mod qr;
+mod svd;
+mod view;
+
+pub use cholesky::{CholeskyDecomposition, LdltDecomposition};
+pub use eigen::{DominantEigenpair, EigenDecomposition};
+pub use index::{Columns, IndexedIter};
+pub use lu::LuDecomposition;
+pub use multiply::{MulStrategy, WrappingNum};
+pub use qr::{LeastSquares, QrDecomposition, QrMethod};
+pub use svd::SingularValueDecomposition;
+pub use view::{MatrixView, MatrixViewMut};
+
 /// A simple *m x n* Matrix implementation, with *m* `rows` and *n* `cols`.
 ///
 /// # Type parameters
@@ -30,6 +54,23 @@ use num_traits::Num;
 ///
 /// # }
 /// ```
+///
+/// Matrices of numbers support the usual arithmetic operators, on owned
+/// values or references. Operators panic if the dimensions do not agree; use
+/// `checked_add`, `checked_sub` or `checked_mul` to get an error instead.
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let a = matrix![[1, 2], [3, 4]];
+/// let b = Matrix::identity(2);
+///
+/// assert_eq!(&a * &b, a);
+/// assert_eq!(&a + &b, matrix![[2, 2], [3, 5]]);
+/// assert_eq!(-&a * 2, matrix![[-2, -4], [-6, -8]]);
+/// # }
+/// ```
 /// 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd)]
 pub struct Matrix<T> {
@@ -38,18 +79,25 @@ pub struct Matrix<T> {
     data: Vec<T>
 }
 
+/// Read access to the values of a matrix, implemented by `Matrix` and by
+/// the `MatrixView` and `MatrixViewMut` views into one.
 pub trait IsMatrix<T> {
     fn rows(&self) -> usize;
     fn cols(&self) -> usize;
     fn get(&self, row: usize, col: usize) -> Option<&T>;
-    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T>;
-    fn set(&mut self, row: usize, col: usize, value: T) -> Option<&T>;
-    fn get_row(&self, row: usize) -> Result<RowIterator<T>, Box<dyn Error>>;
-    fn get_col(&self, col: usize) -> Result<ColumnIterator<T>, Box<dyn Error>>;
-    fn swap_rows(&mut self, row_a: usize, row_b: usize) -> Result<(), Box<dyn Error>>;
-    fn swap_cols(&mut self, col_a: usize, col_b: usize) -> Result<(), Box<dyn Error>>;
+    fn get_row(&self, row: usize) -> Result<RowIterator<'_, T>, Error>;
+    fn get_col(&self, col: usize) -> Result<ColumnIterator<'_, T>, Error>;
     fn transpose(&self) -> Matrix<&T>;
     fn apply<F: FnMut(&T)>(&self, func: F);
+}
+
+/// Write access to the values of a matrix, implemented by `Matrix` and
+/// `MatrixViewMut`.
+pub trait IsMatrixMut<T>: IsMatrix<T> {
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T>;
+    fn set(&mut self, row: usize, col: usize, value: T) -> Option<&T>;
+    fn swap_rows(&mut self, row_a: usize, row_b: usize) -> Result<(), Error>;
+    fn swap_cols(&mut self, col_a: usize, col_b: usize) -> Result<(), Error>;
     fn apply_mut<F: FnMut(&mut T)>(&mut self, func: F);
 }
 
@@ -122,18 +170,17 @@ impl<T> Matrix<T> {
     }
 
     /// Constructs an *m x n* `Matrix`, with *m* `rows` and *n* `cols` 
-    /// where data is populated from an iterator.  
+    /// where data is taken from an existing vector.  
     /// 
     /// The matrix values are set row by row.  
     /// 
-    /// Only `rows * cols` values will be consumed from the iterator.
-    /// 
     /// # Returns
     /// Returns a `Matrix<T>` of size `rows` *x* `cols`
     ///
     /// # Panics
     /// Panics if either `rows` or `cols` are zero or less.
-    /// Panics if the iterator does not have at least `rows * cols` values.
+    /// Panics if `data` does not have exactly `rows * cols` values. Use
+    /// `Matrix::try_from_vec` to get an error instead.
     ///
     /// # Examples
     /// ```
@@ -147,6 +194,7 @@ impl<T> Matrix<T> {
     /// ```
     pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Matrix<T> {
         assert!(rows > 0 && cols > 0);
+        assert_eq!(data.len(), rows * cols);
 
         Matrix {
             rows,
@@ -155,6 +203,41 @@ impl<T> Matrix<T> {
         }
     }
 
+    /// Constructs an *m x n* `Matrix`, with *m* `rows` and *n* `cols` 
+    /// where data is taken from an existing vector.  
+    /// 
+    /// # Returns
+    /// Returns a `Matrix<T>` of size `rows` *x* `cols`
+    ///
+    /// # Errors
+    /// - `Error::ZeroDimension`: If either `rows` or `cols` are zero
+    /// - `Error::LengthMismatch`: If `data` does not have exactly
+    ///   `rows * cols` values
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::error::Error;
+    /// let matrix = Matrix::try_from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+    /// assert_eq!(matrix.get(1, 1).unwrap(), &4);
+    ///
+    /// let too_short = Matrix::try_from_vec(2, 2, vec![1, 2, 3]);
+    /// assert_eq!(too_short, Err(Error::LengthMismatch { expected: 4, found: 3 }));
+    ///
+    /// let empty = Matrix::<i32>::try_from_vec(0, 2, vec![]);
+    /// assert_eq!(empty, Err(Error::ZeroDimension { rows: 0, cols: 2 }));
+    /// ```
+    pub fn try_from_vec(rows: usize, cols: usize, data: Vec<T>) -> Result<Matrix<T>, Error> {
+        if rows == 0 || cols == 0 {
+            return Err(Error::ZeroDimension { rows, cols });
+        }
+        if data.len() != rows * cols {
+            return Err(Error::LengthMismatch { expected: rows * cols, found: data.len() });
+        }
+
+        Ok(Matrix { rows, cols, data })
+    }
+
     /// Constructs an identity matrix of size `size` *x* `size`.
     /// 
     /// The matrix must be square so only one dimension is needed.
@@ -178,6 +261,8 @@ impl<T> Matrix<T> {
     where
         T: Default + Clone + Num
     {
+        assert!(size > 0);
+
         let mut data: Vec<T> = vec![num_traits::zero(); size * size];
 
         for i in 0..size {
@@ -243,6 +328,109 @@ impl<T> IsMatrix<T> for Matrix<T> {
         }
     }
 
+    /// Gets an iterator of values from `row`.
+    /// 
+    /// # Returns
+    /// Returns an iterator of the values in `row` if successful
+    /// 
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is outside the bounds of the `Matrix`
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// let matrix: Matrix<i32> = Matrix::from_iter(3, 6, 0..);
+    /// let result: Vec<i32> = matrix.get_row(1).unwrap().cloned().collect();
+    /// 
+    /// assert_eq!(result, [6, 7, 8, 9, 10, 11]);
+    /// assert!(matrix.get_row(3).is_err());
+    /// ```
+    fn get_row(&self, row: usize) -> Result<RowIterator<'_, T>, Error> {
+        check_index(row, self.rows)?;
+
+        Ok(RowIterator::strided(&self.data, row * self.cols, 1, self.cols))
+    }
+
+    /// Gets an iterator of values from `col`.
+    /// 
+    /// # Returns
+    /// Returns an iterator of the values in `col` if successful
+    /// 
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is outside the bounds of the `Matrix`
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// let matrix: Matrix<i32> = Matrix::from_iter(3, 6, 0..);
+    /// let result: Vec<i32> = matrix.get_col(1).unwrap().cloned().collect();
+    /// 
+    /// assert_eq!(result, [1, 7, 13]);
+    /// assert!(matrix.get_col(6).is_err());
+    /// ```
+    fn get_col(&self, col: usize) -> Result<ColumnIterator<'_, T>, Error> {
+        check_index(col, self.cols)?;
+
+        Ok(ColumnIterator::strided(&self.data, col, self.cols, self.rows))
+    }
+
+    /// Transposes the `Matrix`.
+    /// 
+    /// Take an *m x n* Matrix *M* and return the *transpose* Matrix *M*<sup>T</sup>
+    /// of size *n x m*.
+    /// 
+    /// # Returns
+    /// Returns a new `Matrix<&T>' that is the transpose of the input `Matrix`. Values
+    /// in the new matrix are references to the original matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// let matrix: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
+    /// let transpose = matrix.transpose();
+    ///
+    /// assert_eq!(matrix.rows(), transpose.cols());
+    /// assert_eq!(matrix.cols(), transpose.rows());
+    ///     
+    /// assert_eq!(matrix.get(0, 0).unwrap(), *transpose.get(0, 0).unwrap());
+    /// assert_eq!(matrix.get(0, 2).unwrap(), *transpose.get(2, 0).unwrap());
+    /// ```
+    fn transpose(&self) -> Matrix<&T> {
+        Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            data: {
+                let mut data = Vec::with_capacity(self.cols * self.rows);
+                for col in 0..self.cols {
+                    let column_iter = self.get_col(col).unwrap();
+                    data.extend(column_iter);
+                }
+                data
+            },
+        }
+    }
+
+    /// Applys a function to all values of the `Matrix`.  
+    /// 
+    /// Cells are provided as immutable references to the function,
+    /// if you want to modify the cells, use `apply_mut`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// // Get the sum of all cells
+    /// let matrix = Matrix::from_iter(3, 6, 0..);
+    /// let mut sum = 0;
+    /// matrix.apply(|n| sum += *n);
+    /// 
+    /// assert_eq!(sum, 153);
+    /// ```
+    fn apply<F: FnMut(&T)>(&self, func: F) {
+        self.data.iter().for_each(func);
+    }
+}
+
+impl<T> IsMatrixMut<T> for Matrix<T> {
     /// Gets a *mutable* reference to the value at specified `row` and `column`.  
     /// 
     /// # Returns
@@ -290,85 +478,30 @@ impl<T> IsMatrix<T> for Matrix<T> {
         }
     }
 
-    /// Gets an iterator of values from `row`.
-    /// 
-    /// # Returns
-    /// Returns an iterator of the values in `row` if successful
-    /// 
-    /// # Panics
-    /// Panics if `row` is outside the bounds of the `Matrix`
-    ///
-    /// # Examples
-    /// ```
-    /// # use crate::algorithms::data_structures::matrix::*;
-    /// let matrix: Matrix<i32> = Matrix::from_iter(3, 6, 0..);
-    /// let result: Vec<i32> = matrix.get_row(1).unwrap().cloned().collect();
-    /// 
-    /// assert_eq!(result, [6, 7, 8, 9, 10, 11]);
-    /// ```
-    fn get_row(&self, row: usize) -> Result<RowIterator<T>, Box<dyn Error>> {
-        assert!(row < self.rows);
-
-        let iter = RowIterator {
-            matrix: self,
-            current_col: 0,
-            current_row: row,
-        };
-
-        Ok(iter)
-    }
-
-    /// Gets an iterator of values from `col`.
-    /// 
-    /// # Returns
-    /// Returns an iterator of the values in `col` if successful
-    /// 
-    /// # Panics
-    /// Panics if `col` is outside the bounds of the `Matrix`
-    ///
-    /// # Examples
-    /// ```
-    /// # use crate::algorithms::data_structures::matrix::*;
-    /// let matrix: Matrix<i32> = Matrix::from_iter(3, 6, 0..);
-    /// let result: Vec<i32> = matrix.get_col(1).unwrap().cloned().collect();
-    /// 
-    /// assert_eq!(result, [1, 7, 13]);
-    /// ```
-    fn get_col(&self, col: usize) -> Result<ColumnIterator<T>, Box<dyn Error>> {
-        assert!(col < self.cols);
-
-        let iter = ColumnIterator {
-            matrix: self,
-            current_col: col,
-            current_row: 0,
-        };
-
-        Ok(iter)
-    }
-
     /// Swaps the specified rows (`row_a` and `row_b`) of the `Matrix`.
     /// 
     /// # Returns
     /// Returns `()` if successful.
     /// 
-    /// # Panics
-    /// Panics if either `row_a` or `row_b` are outside `Matrix`.
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either `row_a` or `row_b` are outside `Matrix`.
     /// 
     /// # Examples
     /// ```
     /// # use crate::algorithms::data_structures::matrix::*;
     /// let mut matrix: Matrix<usize> = Matrix::from_iter(2, 2, 0..);
-    /// matrix.swap_rows(0, 1);
+    /// matrix.swap_rows(0, 1).unwrap();
     /// 
     /// assert_eq!(matrix.get(0, 0).unwrap(), &2);
     /// assert_eq!(matrix.get(1, 1).unwrap(), &1);
+    /// assert!(matrix.swap_rows(0, 2).is_err());
     /// ```
-    fn swap_rows(&mut self, row_a: usize, row_b: usize) -> Result<(), Box<dyn Error>> {
-        assert!(row_a < self.rows);
-        assert!(row_b < self.rows);
+    fn swap_rows(&mut self, row_a: usize, row_b: usize) -> Result<(), Error> {
+        check_index(row_a, self.rows)?;
+        check_index(row_b, self.rows)?;
 
         for col in 0..self.cols {
-            self.data.swap(row_a + col, (row_b * self.cols) + col);
+            self.data.swap((row_a * self.cols) + col, (row_b * self.cols) + col);
         }
 
         Ok(())
@@ -380,85 +513,31 @@ impl<T> IsMatrix<T> for Matrix<T> {
     /// # Returns
     /// Returns `()` if successful.
     /// 
-    /// # Panics
-    /// Panics if either `row_a` or `row_b` are outside `Matrix`.
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either `col_a` or `col_b` are outside `Matrix`.
     /// 
     /// # Examples
     /// ```
     /// # use crate::algorithms::data_structures::matrix::*;
     /// let mut matrix: Matrix<usize> = Matrix::from_iter(2, 2, 0..);
-    /// matrix.swap_cols(0, 1);
+    /// matrix.swap_cols(0, 1).unwrap();
     /// 
     /// assert_eq!(matrix.get(0, 0).unwrap(), &1);
     /// assert_eq!(matrix.get(1, 1).unwrap(), &2);
+    /// assert!(matrix.swap_cols(0, 2).is_err());
     /// ```
-    fn swap_cols(&mut self, col_a: usize, col_b: usize) -> Result<(), Box<dyn Error>> {
-        assert!(col_a < self.cols);
-        assert!(col_b < self.cols);
+    fn swap_cols(&mut self, col_a: usize, col_b: usize) -> Result<(), Error> {
+        check_index(col_a, self.cols)?;
+        check_index(col_b, self.cols)?;
 
         for row in 0..self.rows {
-            self.data.swap((row * self.rows) + col_a, (row * self.rows) + col_b);
+            self.data.swap((row * self.cols) + col_a, (row * self.cols) + col_b);
         }
 
         Ok(())
 
     }
 
-    /// Transposes the `Matrix`.
-    /// 
-    /// Take an *m x n* Matrix *M* and return the *transpose* Matrix *M*<sup>T</sup>
-    /// of size *n x m*.
-    /// 
-    /// # Returns
-    /// Returns a new `Matrix<&T>' that is the transpose of the input `Matrix`. Values
-    /// in the new matrix are references to the original matrix.
-    ///
-    /// # Examples
-    /// ```
-    /// # use crate::algorithms::data_structures::matrix::*;
-    /// let matrix: Matrix<usize> = Matrix::from_iter(3, 6, 0..);
-    /// let transpose = matrix.transpose();
-    ///
-    /// assert_eq!(matrix.rows(), transpose.cols());
-    /// assert_eq!(matrix.cols(), transpose.rows());
-    ///     
-    /// assert_eq!(matrix.get(0, 0).unwrap(), *transpose.get(0, 0).unwrap());
-    /// assert_eq!(matrix.get(0, 2).unwrap(), *transpose.get(2, 0).unwrap());
-    /// ```
-    fn transpose(&self) -> Matrix<&T> {
-        Matrix {
-            rows: self.cols,
-            cols: self.rows,
-            data: {
-                let mut data = Vec::with_capacity(self.cols * self.rows);
-                for col in 0..self.cols {
-                    let column_iter = self.get_col(col).unwrap();
-                    data.extend(column_iter);
-                }
-                data
-            },
-        }
-    }
-
-    /// Applys a function to all values of the `Matrix`.  
-    /// 
-    /// Cells are provided as immutable references to the function,
-    /// if you want to modify the cells, use `apply_mut`.
-    ///
-    /// # Examples
-    /// ```
-    /// # use crate::algorithms::data_structures::matrix::*;
-    /// // Get the sum of all cells
-    /// let matrix = Matrix::from_iter(3, 6, 0..);
-    /// let mut sum = 0;
-    /// matrix.apply(|n| sum += *n);
-    /// 
-    /// assert_eq!(sum, 153);
-    /// ```
-    fn apply<F: FnMut(&T)>(&self, mut func: F) {
-        self.data.iter().for_each(|n| func(n));
-    }
-
     /// Applys a function to modify all values of the `Matrix`.
     /// 
     /// Matrix values are provided as mutable references to the function,
@@ -475,8 +554,8 @@ impl<T> IsMatrix<T> for Matrix<T> {
     /// assert_eq!(matrix.get(0, 1).unwrap(), &2);
     /// assert_eq!(matrix.get(0, 2).unwrap(), &4);
     /// ```
-    fn apply_mut<F: FnMut(&mut T)>(&mut self, mut func: F) {
-        self.data.iter_mut().for_each(|n| func(n));
+    fn apply_mut<F: FnMut(&mut T)>(&mut self, func: F) {
+        self.data.iter_mut().for_each(func);
     }
 }
 
@@ -539,44 +618,133 @@ macro_rules! matrix {
 
 
 
+/// An iterator over the values of one column of a matrix or matrix view.
 pub struct ColumnIterator<'a, T> {
-    matrix: &'a Matrix<T>,
-    current_col: usize,
-    current_row: usize,
+    values: Values<'a, T>,
 }
 
 impl<'a, T> Iterator for ColumnIterator<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_col >= self.matrix.cols || self.current_row >= self.matrix.rows {
-            None
-        } else {
-            let item = self.matrix.get(self.current_row, self.current_col);
-            self.current_row += 1;
+        self.values.next()
+    }
 
-            item
-        }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
     }
 }
 
+impl<T> ExactSizeIterator for ColumnIterator<'_, T> {}
+
+/// An iterator over the values of one row of a matrix or matrix view.
 pub struct RowIterator<'a, T> {
-    matrix: &'a Matrix<T>,
-    current_col: usize,
-    current_row: usize,
+    values: Values<'a, T>,
 }
 
 impl<'a, T> Iterator for RowIterator<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_col >= self.matrix.cols || self.current_row >= self.matrix.rows {
-            None
-        } else {
-            let item = self.matrix.get(self.current_row, self.current_col);
-            self.current_col += 1;
+        self.values.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
 
-            item
+impl<T> ExactSizeIterator for RowIterator<'_, T> {}
+
+/// The values walked by a `RowIterator` or `ColumnIterator`.
+enum Values<'a, T> {
+    /// `len` values spaced `stride` apart in a borrowed buffer.
+    Strided(Take<StepBy<Skip<slice::Iter<'a, T>>>>),
+
+    /// `len` values spaced `stride` apart from `ptr`, for mutable views that
+    /// cannot lend out a slice of their buffer.
+    Raw { ptr: *const T, stride: usize, len: usize, marker: PhantomData<&'a T> },
+}
+
+impl<'a, T> Values<'a, T> {
+    fn strided(data: &'a [T], start: usize, stride: usize, len: usize) -> Values<'a, T> {
+        Values::Strided(data.iter().skip(start).step_by(stride.max(1)).take(len))
+    }
+
+    /// # Safety
+    /// `ptr` must point to `len` values spaced `stride` apart, all valid for
+    /// shared reads for `'a`. It is not read if `len` is zero.
+    unsafe fn raw(ptr: *const T, stride: usize, len: usize) -> Values<'a, T> {
+        Values::Raw { ptr, stride, len, marker: PhantomData }
+    }
+}
+
+impl<'a, T> Iterator for Values<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Values::Strided(values) => values.next(),
+            Values::Raw { len: 0, .. } => None,
+            Values::Raw { ptr, stride, len, .. } => {
+                // SAFETY: `raw` requires the `len` values from `ptr` to be
+                // readable for `'a`, so the current one is, and so is the
+                // next one if any remain.
+                let value = unsafe { &**ptr };
+                *len -= 1;
+                if *len > 0 {
+                    *ptr = unsafe { ptr.add(*stride) };
+                }
+                Some(value)
+            }
         }
     }
-}
\ No newline at end of file
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Values::Strided(values) => values.size_hint(),
+            Values::Raw { len, .. } => (*len, Some(*len)),
+        }
+    }
+}
+
+impl<'a, T> RowIterator<'a, T> {
+    /// Iterates over `len` values of `data` spaced `stride` apart from
+    /// `start`.
+    fn strided(data: &'a [T], start: usize, stride: usize, len: usize) -> RowIterator<'a, T> {
+        RowIterator { values: Values::strided(data, start, stride, len) }
+    }
+
+    /// Iterates over `len` values spaced `stride` apart from `ptr`.
+    ///
+    /// # Safety
+    /// As for `Values::raw`.
+    unsafe fn raw(ptr: *const T, stride: usize, len: usize) -> RowIterator<'a, T> {
+        RowIterator { values: unsafe { Values::raw(ptr, stride, len) } }
+    }
+}
+
+impl<'a, T> ColumnIterator<'a, T> {
+    /// Iterates over `len` values of `data` spaced `stride` apart from
+    /// `start`.
+    fn strided(data: &'a [T], start: usize, stride: usize, len: usize) -> ColumnIterator<'a, T> {
+        ColumnIterator { values: Values::strided(data, start, stride, len) }
+    }
+
+    /// Iterates over `len` values spaced `stride` apart from `ptr`.
+    ///
+    /// # Safety
+    /// As for `Values::raw`.
+    unsafe fn raw(ptr: *const T, stride: usize, len: usize) -> ColumnIterator<'a, T> {
+        ColumnIterator { values: unsafe { Values::raw(ptr, stride, len) } }
+    }
+}
+
+/// Checks that `index` lies within a dimension of length `len`.
+pub(crate) fn check_index(index: usize, len: usize) -> Result<(), Error> {
+    if index < len {
+        Ok(())
+    } else {
+        Err(Error::OutOfBounds { index, len })
+    }
+}
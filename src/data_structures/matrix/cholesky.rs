@@ -0,0 +1,275 @@
+use super::Matrix;
+use crate::error::Error;
+
+/// The Cholesky decomposition of a symmetric positive definite matrix *A*,
+/// such that *A = LL*<sup>T</sup>.
+///
+/// *L* is lower triangular with a positive diagonal. Created by
+/// `Matrix::cholesky`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let a = matrix![[4.0, 2.0], [2.0, 10.0]];
+/// let cholesky = a.cholesky().unwrap();
+///
+/// assert_eq!(cholesky.l(), &matrix![[2.0, 0.0], [1.0, 3.0]]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CholeskyDecomposition {
+    l: Matrix<f64>,
+}
+
+impl CholeskyDecomposition {
+    /// Gets the lower triangular factor *L*.
+    pub fn l(&self) -> &Matrix<f64> {
+        &self.l
+    }
+
+    /// Computes the determinant of *A*, as the square of the product of the
+    /// diagonal of *L*.
+    pub fn determinant(&self) -> f64 {
+        let n = self.l.rows;
+        (0..n).map(|i| self.l.data[i * n + i]).product::<f64>().powi(2)
+    }
+
+    /// Solves *AX = B* for *X* by substitution with *L* and then
+    /// *L*<sup>T</sup>. Each column of `b` is a separate right-hand side.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `b` does not have as many rows
+    /// as *A*.
+    pub fn solve(&self, b: &Matrix<f64>) -> Result<Matrix<f64>, Error> {
+        let mut x = rhs(&self.l, b)?;
+        forward(&self.l, &mut x, b.cols, true);
+        backward(&self.l, &mut x, b.cols, true);
+        Ok(Matrix { rows: b.rows, cols: b.cols, data: x })
+    }
+}
+
+/// The LDL<sup>T</sup> decomposition of a symmetric matrix *A*, such that
+/// *A = LDL*<sup>T</sup>.
+///
+/// *L* is unit lower triangular and *D* is diagonal. Unlike Cholesky, no
+/// square roots are taken, so symmetric indefinite matrices can be factored
+/// as long as no pivot is zero. Created by `Matrix::ldlt`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// // Symmetric but indefinite
+/// let a = matrix![[1.0, 2.0], [2.0, 1.0]];
+/// let ldlt = a.ldlt().unwrap();
+///
+/// assert_eq!(ldlt.l(), &matrix![[1.0, 0.0], [2.0, 1.0]]);
+/// assert_eq!(ldlt.d(), &[1.0, -3.0]);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LdltDecomposition {
+    l: Matrix<f64>,
+    d: Vec<f64>,
+}
+
+impl LdltDecomposition {
+    /// Gets the unit lower triangular factor *L*.
+    pub fn l(&self) -> &Matrix<f64> {
+        &self.l
+    }
+
+    /// Gets the diagonal of *D*.
+    pub fn d(&self) -> &[f64] {
+        &self.d
+    }
+
+    /// Computes the determinant of *A*, as the product of the diagonal of
+    /// *D*.
+    pub fn determinant(&self) -> f64 {
+        self.d.iter().product()
+    }
+
+    /// Solves *AX = B* for *X* by substitution with *L*, *D* and then
+    /// *L*<sup>T</sup>. Each column of `b` is a separate right-hand side.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `b` does not have as many rows
+    /// as *A*.
+    pub fn solve(&self, b: &Matrix<f64>) -> Result<Matrix<f64>, Error> {
+        let k = b.cols;
+        let mut x = rhs(&self.l, b)?;
+        forward(&self.l, &mut x, k, false);
+        for (i, d_i) in self.d.iter().enumerate() {
+            x[i * k..(i + 1) * k].iter_mut().for_each(|x| *x /= d_i);
+        }
+        backward(&self.l, &mut x, k, false);
+        Ok(Matrix { rows: b.rows, cols: k, data: x })
+    }
+}
+
+impl Matrix<f64> {
+    /// Returns `true` if the matrix is square and each entry differs from
+    /// its mirror across the diagonal by at most `tolerance`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let a = matrix![[1.0, 2.0], [2.0 + 1e-12, 1.0]];
+    /// assert!(a.is_symmetric(1e-9));
+    /// assert!(!a.is_symmetric(0.0));
+    /// # }
+    /// ```
+    pub fn is_symmetric(&self, tolerance: f64) -> bool {
+        self.rows == self.cols && self.find_asymmetry(tolerance).is_none()
+    }
+
+    /// Computes the Cholesky decomposition of a symmetric positive definite
+    /// matrix.
+    ///
+    /// The matrix must be symmetric up to rounding error; average it with
+    /// its transpose first if it was accumulated in floating point.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix is not square
+    /// - `Error::NotSymmetric`: If the matrix is not symmetric
+    /// - `Error::NotPositiveDefinite`: If the matrix is not positive definite
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::error::Error;
+    /// # fn main() {
+    /// let indefinite = matrix![[1.0, 2.0], [2.0, 1.0]];
+    /// assert_eq!(indefinite.cholesky(), Err(Error::NotPositiveDefinite { index: 1 }));
+    ///
+    /// let asymmetric = matrix![[1.0, 2.0], [0.0, 1.0]];
+    /// assert_eq!(asymmetric.cholesky(), Err(Error::NotSymmetric { row: 0, col: 1 }));
+    /// # }
+    /// ```
+    pub fn cholesky(&self) -> Result<CholeskyDecomposition, Error> {
+        let tolerance = self.check_symmetric()?;
+        let n = self.rows;
+        let mut l = vec![0.0; n * n];
+
+        for j in 0..n {
+            let pivot = self.data[j * n + j] - (0..j).map(|k| l[j * n + k] * l[j * n + k]).sum::<f64>();
+            if pivot <= tolerance {
+                return Err(Error::NotPositiveDefinite { index: j });
+            }
+            let l_jj = pivot.sqrt();
+            l[j * n + j] = l_jj;
+
+            for i in j + 1..n {
+                let dot: f64 = (0..j).map(|k| l[i * n + k] * l[j * n + k]).sum();
+                l[i * n + j] = (self.data[i * n + j] - dot) / l_jj;
+            }
+        }
+
+        Ok(CholeskyDecomposition { l: Matrix { rows: n, cols: n, data: l } })
+    }
+
+    /// Computes the LDL<sup>T</sup> decomposition of a symmetric matrix,
+    /// without pivoting.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix is not square
+    /// - `Error::NotSymmetric`: If the matrix is not symmetric
+    /// - `Error::Singular`: If a pivot is zero, which happens for singular
+    ///   matrices and for some indefinite ones that need pivoting
+    pub fn ldlt(&self) -> Result<LdltDecomposition, Error> {
+        let tolerance = self.check_symmetric()?;
+        let n = self.rows;
+        let mut l = vec![0.0; n * n];
+        let mut d = vec![0.0; n];
+
+        for j in 0..n {
+            let d_j = self.data[j * n + j] - (0..j).map(|k| l[j * n + k] * l[j * n + k] * d[k]).sum::<f64>();
+            if d_j.abs() <= tolerance {
+                return Err(Error::Singular);
+            }
+            d[j] = d_j;
+            l[j * n + j] = 1.0;
+
+            for i in j + 1..n {
+                let dot: f64 = (0..j).map(|k| l[i * n + k] * l[j * n + k] * d[k]).sum();
+                l[i * n + j] = (self.data[i * n + j] - dot) / d_j;
+            }
+        }
+
+        Ok(LdltDecomposition { l: Matrix { rows: n, cols: n, data: l }, d })
+    }
+
+    /// Checks that the matrix is square and symmetric up to rounding error,
+    /// returning the tolerance used.
+    pub(super) fn check_symmetric(&self) -> Result<f64, Error> {
+        self.check_square()?;
+        let tolerance = self.tolerance();
+        match self.find_asymmetry(tolerance) {
+            Some((row, col)) => Err(Error::NotSymmetric { row, col }),
+            None => Ok(tolerance),
+        }
+    }
+
+    /// Finds the first entry above the diagonal that differs from its mirror
+    /// by more than `tolerance`.
+    fn find_asymmetry(&self, tolerance: f64) -> Option<(usize, usize)> {
+        let n = self.rows.min(self.cols);
+        (0..n)
+            .flat_map(|row| (row + 1..n).map(move |col| (row, col)))
+            .find(|&(row, col)| {
+                (self.data[row * self.cols + col] - self.data[col * self.cols + row]).abs() > tolerance
+            })
+    }
+}
+
+/// Copies `b` as the starting point for substitution with the square
+/// triangular matrix `l`.
+fn rhs(l: &Matrix<f64>, b: &Matrix<f64>) -> Result<Vec<f64>, Error> {
+    if b.rows != l.rows {
+        return Err(Error::DimensionMismatch { expected: (l.rows, b.cols), found: (b.rows, b.cols) });
+    }
+    Ok(b.data.clone())
+}
+
+/// Solves *LY = X* in place, where `x` has `k` columns. When `divide` is
+/// `false`, *L* is taken to have a unit diagonal.
+fn forward(l: &Matrix<f64>, x: &mut [f64], k: usize, divide: bool) {
+    let n = l.rows;
+    for i in 0..n {
+        for j in 0..i {
+            let l_ij = l.data[i * n + j];
+            for c in 0..k {
+                x[i * k + c] -= l_ij * x[j * k + c];
+            }
+        }
+        if divide {
+            x[i * k..(i + 1) * k].iter_mut().for_each(|x| *x /= l.data[i * n + i]);
+        }
+    }
+}
+
+/// Solves *L*<sup>T</sup>*Y = X* in place, where `x` has `k` columns. When
+/// `divide` is `false`, *L* is taken to have a unit diagonal.
+fn backward(l: &Matrix<f64>, x: &mut [f64], k: usize, divide: bool) {
+    let n = l.rows;
+    for i in (0..n).rev() {
+        for j in i + 1..n {
+            let l_ji = l.data[j * n + i];
+            for c in 0..k {
+                x[i * k + c] -= l_ji * x[j * k + c];
+            }
+        }
+        if divide {
+            x[i * k..(i + 1) * k].iter_mut().for_each(|x| *x /= l.data[i * n + i]);
+        }
+    }
+}
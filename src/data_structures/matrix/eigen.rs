@@ -0,0 +1,463 @@
+use super::qr::{householder_vector, reflect, reflect_right};
+use super::Matrix;
+use crate::error::Error;
+
+/// Maximum number of Jacobi sweeps over the off-diagonal entries.
+const MAX_SWEEPS: usize = 100;
+
+/// Maximum number of QR iterations spent isolating each eigenvalue of a
+/// Hessenberg matrix.
+const MAX_QR_ITERATIONS: usize = 30;
+
+/// The eigen-decomposition of a symmetric matrix *A*, such that
+/// *A = VΛV*<sup>T</sup>.
+///
+/// The eigenvalues are real and sorted in ascending order, and the columns
+/// of *V* are the matching orthonormal eigenvectors. Created by
+/// `Matrix::symmetric_eigen`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let a = matrix![[2.0, 1.0], [1.0, 2.0]];
+/// let eigen = a.symmetric_eigen().unwrap();
+///
+/// assert!((eigen.values()[0] - 1.0).abs() < 1e-12);
+/// assert!((eigen.values()[1] - 3.0).abs() < 1e-12);
+///
+/// // The eigenvector for 3 is (1, 1) / sqrt(2), up to sign
+/// let v = eigen.vectors();
+/// assert!((v.get(0, 1).unwrap() - v.get(1, 1).unwrap()).abs() < 1e-12);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EigenDecomposition {
+    values: Vec<f64>,
+    vectors: Matrix<f64>,
+}
+
+impl EigenDecomposition {
+    /// Gets the eigenvalues, in ascending order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Gets the eigenvectors, as the columns of a matrix.
+    pub fn vectors(&self) -> &Matrix<f64> {
+        &self.vectors
+    }
+}
+
+/// The dominant eigenvalue of a matrix and its eigenvector, created by
+/// `Matrix::power_iteration`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DominantEigenpair {
+    value: f64,
+    vector: Vec<f64>,
+    iterations: usize,
+}
+
+impl DominantEigenpair {
+    /// Gets the eigenvalue with the largest magnitude.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Gets the matching eigenvector, normalised to unit length.
+    pub fn vector(&self) -> &[f64] {
+        &self.vector
+    }
+
+    /// Gets the number of iterations taken to converge.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+}
+
+impl Matrix<f64> {
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix with
+    /// the cyclic Jacobi method.
+    ///
+    /// Each Jacobi rotation zeroes one off-diagonal pair, and sweeping over
+    /// every pair repeatedly drives the matrix to diagonal form. The method
+    /// is slower than tridiagonal QR but simple and very accurate.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix is not square
+    /// - `Error::NotSymmetric`: If the matrix is not symmetric
+    /// - `Error::NotConverged`: If the off-diagonal entries did not vanish
+    ///   within 100 sweeps
+    pub fn symmetric_eigen(&self) -> Result<EigenDecomposition, Error> {
+        self.check_symmetric()?;
+
+        let n = self.rows;
+        let mut a = self.data.clone();
+        let mut v = Matrix::identity(n).data;
+
+        // Rotations preserve the Frobenius norm, so stop once the
+        // off-diagonal part is negligible next to the whole matrix. Comparing
+        // each entry with its own diagonal would never finish when the
+        // diagonal is (near) zero, as it is for rank-deficient matrices.
+        let threshold = f64::EPSILON * a.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let off_diagonal = |a: &[f64]| {
+            let squares = (0..n).flat_map(|p| (p + 1..n).map(move |q| a[p * n + q] * a[p * n + q]));
+            (2.0 * squares.sum::<f64>()).sqrt()
+        };
+
+        let mut sweeps = 0;
+        while off_diagonal(&a) > threshold {
+            if sweeps == MAX_SWEEPS {
+                return Err(Error::NotConverged { iterations: sweeps });
+            }
+            sweeps += 1;
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (a_pp, a_qq, a_pq) = (a[p * n + p], a[q * n + q], a[p * n + q]);
+                    if a_pq == 0.0 {
+                        continue;
+                    }
+
+                    // Choose the smaller rotation angle that zeroes a_pq.
+                    let theta = (a_qq - a_pp) / (2.0 * a_pq);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    rotate_cols(&mut a, n, p, q, c, s);
+                    rotate_rows(&mut a, n, p, q, c, s);
+                    rotate_cols(&mut v, n, p, q, c, s);
+                    // The rotation zeroes a_pq exactly; don't leave rounding
+                    // residue behind for the next sweep to chase.
+                    a[p * n + q] = 0.0;
+                    a[q * n + p] = 0.0;
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[i * n + i].total_cmp(&a[j * n + j]));
+        let values = order.iter().map(|&i| a[i * n + i]).collect();
+        let vectors = (0..n).flat_map(|row| order.iter().map(move |&col| (row, col)));
+        let vectors = vectors.map(|(row, col)| v[row * n + col]).collect();
+
+        Ok(EigenDecomposition { values, vectors: Matrix { rows: n, cols: n, data: vectors } })
+    }
+
+    /// Computes the eigenvalues of a square matrix, which may be complex.
+    ///
+    /// The matrix is reduced to upper Hessenberg form by Householder
+    /// similarity transforms, then Francis double-shift QR iteration splits
+    /// it into 1 x 1 and 2 x 2 diagonal blocks. Complex eigenvalues come in
+    /// conjugate pairs.
+    ///
+    /// # Returns
+    /// Returns each eigenvalue as a (`real`, `imaginary`) pair, sorted by
+    /// descending real part and then descending imaginary part.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix is not square
+    /// - `Error::NotConverged`: If an eigenvalue was not isolated within 30
+    ///   QR iterations
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// // A rotation by 90 degrees has eigenvalues i and -i
+    /// let rotation = matrix![[0.0, -1.0], [1.0, 0.0]];
+    /// let values = rotation.eigenvalues().unwrap();
+    ///
+    /// assert!(values[0].0.abs() < 1e-12 && (values[0].1 - 1.0).abs() < 1e-12);
+    /// assert!(values[1].0.abs() < 1e-12 && (values[1].1 + 1.0).abs() < 1e-12);
+    /// # }
+    /// ```
+    pub fn eigenvalues(&self) -> Result<Vec<(f64, f64)>, Error> {
+        self.check_square()?;
+
+        let mut values = hessenberg_qr(self.hessenberg(), self.rows)?;
+        values.sort_by(|a, b| b.0.total_cmp(&a.0).then(b.1.total_cmp(&a.1)));
+        Ok(values)
+    }
+
+    /// Finds the eigenvalue with the largest magnitude, and its eigenvector,
+    /// by power iteration.
+    ///
+    /// Repeatedly multiplying a vector by the matrix amplifies its component
+    /// along the dominant eigenvector. Iteration stops once the residual
+    /// ‖*Av - λv*‖ of the Rayleigh quotient *λ* is at most `tolerance`. The
+    /// rate of convergence depends on the ratio between the two largest
+    /// eigenvalue magnitudes, and iteration cannot converge if they are
+    /// equal but the eigenvalues differ.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix is not square
+    /// - `Error::NotConverged`: If the residual was still above `tolerance`
+    ///   after `max_iterations`
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let a = matrix![[2.0, 0.0], [0.0, -5.0]];
+    /// let pair = a.power_iteration(1e-10, 1000).unwrap();
+    ///
+    /// assert!((pair.value() + 5.0).abs() < 1e-10);
+    /// assert!((pair.vector()[1].abs() - 1.0).abs() < 1e-10);
+    /// # }
+    /// ```
+    pub fn power_iteration(&self, tolerance: f64, max_iterations: usize) -> Result<DominantEigenpair, Error> {
+        self.check_square()?;
+
+        let n = self.rows;
+        // Avoid starting orthogonal to the dominant eigenvector by using
+        // uneven weights.
+        let mut vector: Vec<f64> = (0..n).map(|i| 1.0 + i as f64 / n as f64).collect();
+        normalise(&mut vector);
+
+        for iterations in 1..=max_iterations {
+            let product = self.mul_vec(&vector);
+            let value: f64 = vector.iter().zip(&product).map(|(v, av)| v * av).sum();
+            let residual = product
+                .iter()
+                .zip(&vector)
+                .map(|(av, v)| (av - value * v).powi(2))
+                .sum::<f64>()
+                .sqrt();
+            if residual <= tolerance {
+                return Ok(DominantEigenpair { value, vector, iterations });
+            }
+
+            vector = product;
+            normalise(&mut vector);
+        }
+
+        Err(Error::NotConverged { iterations: max_iterations })
+    }
+
+    /// Reduces a square matrix to upper Hessenberg form, zero below the
+    /// first subdiagonal, with the same eigenvalues.
+    fn hessenberg(&self) -> Vec<f64> {
+        let n = self.rows;
+        let mut a = self.data.clone();
+        for k in 0..n.saturating_sub(2) {
+            let v = householder_vector((k + 1..n).map(|i| a[i * n + k]).collect());
+            reflect(&mut a, n, k + 1, 0, &v);
+            reflect_right(&mut a, n, k + 1, 0, &v);
+            for i in k + 2..n {
+                a[i * n + k] = 0.0;
+            }
+        }
+        a
+    }
+
+    /// Multiplies the matrix by a column vector.
+    fn mul_vec(&self, x: &[f64]) -> Vec<f64> {
+        self.data
+            .chunks_exact(self.cols)
+            .map(|row| row.iter().zip(x).map(|(a, x)| a * x).sum())
+            .collect()
+    }
+}
+
+/// Scales `x` to unit length, unless it is zero.
+fn normalise(x: &mut [f64]) {
+    let norm = x.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        x.iter_mut().for_each(|x| *x /= norm);
+    }
+}
+
+/// Replaces columns `p` and `q` of a row-major matrix with `n` columns by
+/// `c * p - s * q` and `s * p + c * q`.
+fn rotate_cols(a: &mut [f64], n: usize, p: usize, q: usize, c: f64, s: f64) {
+    for row in a.chunks_exact_mut(n) {
+        let (a_p, a_q) = (row[p], row[q]);
+        row[p] = c * a_p - s * a_q;
+        row[q] = s * a_p + c * a_q;
+    }
+}
+
+/// Replaces rows `p` and `q` of a row-major matrix with `n` columns by
+/// `c * p - s * q` and `s * p + c * q`.
+fn rotate_rows(a: &mut [f64], n: usize, p: usize, q: usize, c: f64, s: f64) {
+    for k in 0..n {
+        let (a_p, a_q) = (a[p * n + k], a[q * n + k]);
+        a[p * n + k] = c * a_p - s * a_q;
+        a[q * n + k] = s * a_p + c * a_q;
+    }
+}
+
+/// Finds the eigenvalues of an upper Hessenberg matrix `a` of size `n` by
+/// Francis double-shift QR iteration, after the EISPACK routine `hqr`.
+fn hessenberg_qr(mut a: Vec<f64>, n: usize) -> Result<Vec<(f64, f64)>, Error> {
+    let at = |i: usize, j: usize| i * n + j;
+    let sign = |a: f64, b: f64| if b >= 0.0 { a.abs() } else { -a.abs() };
+
+    let norm: f64 = (0..n)
+        .flat_map(|i| (i.saturating_sub(1)..n).map(move |j| (i, j)))
+        .map(|(i, j)| a[at(i, j)].abs())
+        .sum();
+    let mut values = Vec::with_capacity(n);
+    // Shifts applied to the whole matrix by exceptional shifts.
+    let mut t = 0.0;
+    let mut iterations = 0;
+    let mut nn = n;
+
+    while nn > 0 {
+        let last = nn - 1;
+
+        // Look for a negligible subdiagonal element to split the matrix.
+        let mut l = last;
+        while l > 0 {
+            let mut s = a[at(l - 1, l - 1)].abs() + a[at(l, l)].abs();
+            if s == 0.0 {
+                s = norm;
+            }
+            if a[at(l, l - 1)].abs() <= f64::EPSILON * s {
+                a[at(l, l - 1)] = 0.0;
+                break;
+            }
+            l -= 1;
+        }
+
+        let mut x = a[at(last, last)];
+        if l == last {
+            // A 1 x 1 block has split off.
+            values.push((x + t, 0.0));
+            nn -= 1;
+            iterations = 0;
+            continue;
+        }
+
+        let mut y = a[at(last - 1, last - 1)];
+        let mut w = a[at(last, last - 1)] * a[at(last - 1, last)];
+        if l == last - 1 {
+            // A 2 x 2 block has split off; solve its characteristic equation.
+            let p = 0.5 * (y - x);
+            let q = p * p + w;
+            let z = q.abs().sqrt();
+            x += t;
+            if q >= 0.0 {
+                let z = p + sign(z, p);
+                let second = if z != 0.0 { x - w / z } else { x + z };
+                values.push((x + z, 0.0));
+                values.push((second, 0.0));
+            } else {
+                values.push((x + p, z));
+                values.push((x + p, -z));
+            }
+            nn -= 2;
+            iterations = 0;
+            continue;
+        }
+
+        if iterations == MAX_QR_ITERATIONS {
+            return Err(Error::NotConverged { iterations });
+        }
+        if iterations == 10 || iterations == 20 {
+            // Exceptional shift, to break cycles.
+            t += x;
+            for i in 0..=last {
+                a[at(i, i)] -= x;
+            }
+            let s = a[at(last, last - 1)].abs() + a[at(last - 1, last - 2)].abs();
+            x = 0.75 * s;
+            y = x;
+            w = -0.4375 * s * s;
+        }
+        iterations += 1;
+
+        // Look for two consecutive small subdiagonal elements, and form the
+        // first column of the double-shifted matrix.
+        let mut m = last - 2;
+        let (mut p, mut q, mut r);
+        loop {
+            let z = a[at(m, m)];
+            let r0 = x - z;
+            let s0 = y - z;
+            p = (r0 * s0 - w) / a[at(m + 1, m)] + a[at(m, m + 1)];
+            q = a[at(m + 1, m + 1)] - z - r0 - s0;
+            r = a[at(m + 2, m + 1)];
+            let s = p.abs() + q.abs() + r.abs();
+            p /= s;
+            q /= s;
+            r /= s;
+            if m == l {
+                break;
+            }
+            let u = a[at(m, m - 1)].abs() * (q.abs() + r.abs());
+            let v = p.abs() * (a[at(m - 1, m - 1)].abs() + z.abs() + a[at(m + 1, m + 1)].abs());
+            if u <= f64::EPSILON * v {
+                break;
+            }
+            m -= 1;
+        }
+
+        for i in m..last - 1 {
+            a[at(i + 2, i)] = 0.0;
+            if i != m {
+                a[at(i + 2, i - 1)] = 0.0;
+            }
+        }
+
+        // Chase the bulge down the matrix with 3 x 3 Householder reflections.
+        let mut scale = 0.0;
+        for k in m..last {
+            if k != m {
+                p = a[at(k, k - 1)];
+                q = a[at(k + 1, k - 1)];
+                r = if k + 1 != last { a[at(k + 2, k - 1)] } else { 0.0 };
+                scale = p.abs() + q.abs() + r.abs();
+                if scale != 0.0 {
+                    p /= scale;
+                    q /= scale;
+                    r /= scale;
+                }
+            }
+
+            let s = sign((p * p + q * q + r * r).sqrt(), p);
+            if s == 0.0 {
+                continue;
+            }
+            if k == m {
+                if l != m {
+                    a[at(k, k - 1)] = -a[at(k, k - 1)];
+                }
+            } else {
+                a[at(k, k - 1)] = -s * scale;
+            }
+            p += s;
+            let (x, y, z) = (p / s, q / s, r / s);
+            q /= p;
+            r /= p;
+
+            for j in k..=last {
+                let mut p = a[at(k, j)] + q * a[at(k + 1, j)];
+                if k + 1 != last {
+                    p += r * a[at(k + 2, j)];
+                    a[at(k + 2, j)] -= p * z;
+                }
+                a[at(k + 1, j)] -= p * y;
+                a[at(k, j)] -= p * x;
+            }
+
+            for i in l..=last.min(k + 3) {
+                let mut p = x * a[at(i, k)] + y * a[at(i, k + 1)];
+                if k + 1 != last {
+                    p += z * a[at(i, k + 2)];
+                    a[at(i, k + 2)] -= p * r;
+                }
+                a[at(i, k + 1)] -= p * q;
+                a[at(i, k)] -= p;
+            }
+        }
+    }
+
+    Ok(values)
+}
@@ -0,0 +1,212 @@
+use std::iter::{Enumerate, StepBy};
+use std::ops::{Index, IndexMut};
+use std::slice::{self, ChunksExact};
+
+use super::{check_index, ColumnIterator, IsMatrix, Matrix};
+use crate::error::Error;
+
+impl<T> Matrix<T> {
+    /// Gets the values of the matrix as a row-major slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let matrix = matrix![[1, 2], [3, 4]];
+    /// assert_eq!(matrix.as_slice(), &[1, 2, 3, 4]);
+    /// # }
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Gets the values of the matrix as a mutable row-major slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Gets an iterator over the rows of the matrix, each as a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let matrix = matrix![[1, 2], [3, 4], [5, 6]];
+    /// let sums: Vec<i32> = matrix.rows_iter().map(|row| row.iter().sum()).collect();
+    ///
+    /// assert_eq!(sums, [3, 7, 11]);
+    /// # }
+    /// ```
+    pub fn rows_iter(&self) -> ChunksExact<'_, T> {
+        self.data.chunks_exact(self.cols)
+    }
+
+    /// Gets an iterator over the columns of the matrix, each as a
+    /// `ColumnIterator`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let matrix = matrix![[1, 2], [3, 4], [5, 6]];
+    /// let sums: Vec<i32> = matrix.cols_iter().map(|col| col.sum()).collect();
+    ///
+    /// assert_eq!(sums, [9, 12]);
+    /// # }
+    /// ```
+    pub fn cols_iter(&self) -> Columns<'_, T> {
+        Columns { matrix: self, col: 0 }
+    }
+
+    /// Gets a mutable iterator over the values in `row`.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is outside the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let mut matrix = matrix![[1, 2], [3, 4]];
+    /// matrix.row_mut(1).unwrap().for_each(|x| *x *= 10);
+    ///
+    /// assert_eq!(matrix, matrix![[1, 2], [30, 40]]);
+    /// assert!(matrix.row_mut(2).is_err());
+    /// # }
+    /// ```
+    pub fn row_mut(&mut self, row: usize) -> Result<slice::IterMut<'_, T>, Error> {
+        check_index(row, self.rows)?;
+
+        Ok(self.data[row * self.cols..(row + 1) * self.cols].iter_mut())
+    }
+
+    /// Gets a mutable iterator over the values in `col`.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is outside the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let mut matrix = matrix![[1, 2], [3, 4]];
+    /// matrix.col_mut(1).unwrap().for_each(|x| *x = 0);
+    ///
+    /// assert_eq!(matrix, matrix![[1, 0], [3, 0]]);
+    /// assert!(matrix.col_mut(2).is_err());
+    /// # }
+    /// ```
+    pub fn col_mut(&mut self, col: usize) -> Result<StepBy<slice::IterMut<'_, T>>, Error> {
+        check_index(col, self.cols)?;
+
+        Ok(self.data[col..].iter_mut().step_by(self.cols))
+    }
+
+    /// Gets an iterator over the values of the matrix, row by row, with
+    /// their (`row`, `col`) positions.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let matrix = matrix![[1, 2], [3, 4]];
+    /// let trace: i32 = matrix.iter_indexed().filter(|((i, j), _)| i == j).map(|(_, x)| x).sum();
+    ///
+    /// assert_eq!(trace, 5);
+    /// assert_eq!(matrix.iter_indexed().last(), Some(((1, 1), &4)));
+    /// # }
+    /// ```
+    pub fn iter_indexed(&self) -> IndexedIter<'_, T> {
+        IndexedIter { values: self.data.iter().enumerate(), cols: self.cols }
+    }
+}
+
+/// Indexes the matrix by (`row`, `col`).
+///
+/// # Panics
+/// Panics if either `row` or `col` is outside the matrix. Use `get` to get
+/// `None` instead.
+///
+/// # Examples
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let mut matrix = matrix![[1, 2], [3, 4]];
+/// matrix[(1, 0)] += 10;
+///
+/// assert_eq!(matrix[(0, 1)], 2);
+/// assert_eq!(matrix[(1, 0)], 13);
+/// # }
+/// ```
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.get(row, col) {
+            Some(value) => value,
+            None => panic!("index ({}, {}) is out of bounds for a {} x {} matrix", row, col, self.rows, self.cols),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        if row >= rows || col >= cols {
+            panic!("index ({}, {}) is out of bounds for a {} x {} matrix", row, col, rows, cols);
+        }
+        &mut self.data[row * cols + col]
+    }
+}
+
+/// An iterator over the columns of a matrix, created by
+/// `Matrix::cols_iter`.
+pub struct Columns<'a, T> {
+    matrix: &'a Matrix<T>,
+    col: usize,
+}
+
+impl<'a, T> Iterator for Columns<'a, T> {
+    type Item = ColumnIterator<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let column = self.matrix.get_col(self.col).ok()?;
+        self.col += 1;
+        Some(column)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.matrix.cols - self.col;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Columns<'_, T> {}
+
+/// An iterator over the values of a matrix with their (`row`, `col`)
+/// positions, created by `Matrix::iter_indexed`.
+pub struct IndexedIter<'a, T> {
+    values: Enumerate<slice::Iter<'a, T>>,
+    cols: usize,
+}
+
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next().map(|(index, value)| ((index / self.cols, index % self.cols), value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IndexedIter<'_, T> {}
@@ -0,0 +1,322 @@
+use super::{IsMatrixMut, Matrix};
+use crate::error::Error;
+
+/// The LU decomposition of a square matrix *A* with partial pivoting, such
+/// that *PA = LU*.
+///
+/// *L* is unit lower triangular, *U* is upper triangular and *P* is the
+/// permutation that reorders the rows of *A*; row `i` of *PA* is row
+/// `permutation()[i]` of *A*. Created by `Matrix::lu`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let a = matrix![[0.0, 2.0], [3.0, 4.0]];
+/// let lu = a.lu().unwrap();
+///
+/// // The zero pivot forces the rows to be swapped
+/// assert_eq!(lu.permutation(), &[1, 0]);
+/// assert_eq!(lu.l(), &matrix![[1.0, 0.0], [0.0, 1.0]]);
+/// assert_eq!(lu.u(), &matrix![[3.0, 4.0], [0.0, 2.0]]);
+/// assert_eq!(lu.determinant(), -6.0);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LuDecomposition {
+    l: Matrix<f64>,
+    u: Matrix<f64>,
+    permutation: Vec<usize>,
+    swaps: usize,
+    tolerance: f64,
+}
+
+impl LuDecomposition {
+    /// Gets the unit lower triangular factor *L*.
+    pub fn l(&self) -> &Matrix<f64> {
+        &self.l
+    }
+
+    /// Gets the upper triangular factor *U*.
+    pub fn u(&self) -> &Matrix<f64> {
+        &self.u
+    }
+
+    /// Gets the row permutation, where row `i` of *PA* is row
+    /// `permutation()[i]` of *A*.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Builds the permutation matrix *P*.
+    pub fn permutation_matrix(&self) -> Matrix<f64> {
+        let n = self.permutation.len();
+        let mut p = Matrix::new(n, n);
+        for (i, &j) in self.permutation.iter().enumerate() {
+            p.set(i, j, 1.0);
+        }
+        p
+    }
+
+    /// Returns `true` if a pivot of *U* is zero, up to rounding error.
+    pub fn is_singular(&self) -> bool {
+        (0..self.u.rows).any(|k| self.u.data[k * self.u.cols + k].abs() <= self.tolerance)
+    }
+
+    /// Computes the determinant of *A*, as the product of the pivots of *U*
+    /// with the sign of the permutation.
+    pub fn determinant(&self) -> f64 {
+        let n = self.u.rows;
+        let product: f64 = (0..n).map(|k| self.u.data[k * n + k]).product();
+        if self.swaps.is_multiple_of(2) { product } else { -product }
+    }
+
+    /// Solves *AX = B* for *X* by forward and back substitution. Each column
+    /// of `b` is a separate right-hand side.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If `b` does not have as many rows as *A*
+    /// - `Error::Singular`: If *A* is singular
+    pub fn solve(&self, b: &Matrix<f64>) -> Result<Matrix<f64>, Error> {
+        let n = self.u.rows;
+        if b.rows != n {
+            return Err(Error::DimensionMismatch { expected: (n, b.cols), found: (b.rows, b.cols) });
+        }
+        if self.is_singular() {
+            return Err(Error::Singular);
+        }
+
+        let k = b.cols;
+        let mut x: Vec<f64> = self.permutation
+            .iter()
+            .flat_map(|&row| b.data[row * k..(row + 1) * k].iter().copied())
+            .collect();
+
+        // Forward substitution with the unit diagonal of L.
+        for i in 0..n {
+            for j in 0..i {
+                let l_ij = self.l.data[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= l_ij * x[j * k + c];
+                }
+            }
+        }
+
+        // Back substitution with U.
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let u_ij = self.u.data[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= u_ij * x[j * k + c];
+                }
+            }
+            let pivot = self.u.data[i * n + i];
+            for c in 0..k {
+                x[i * k + c] /= pivot;
+            }
+        }
+
+        Ok(Matrix { rows: n, cols: k, data: x })
+    }
+
+    /// Computes the inverse of *A*.
+    ///
+    /// # Errors
+    /// Returns `Error::Singular` if *A* is singular.
+    pub fn inverse(&self) -> Result<Matrix<f64>, Error> {
+        self.solve(&Matrix::identity(self.u.rows))
+    }
+}
+
+impl Matrix<f64> {
+    /// Computes the LU decomposition of a square matrix with partial
+    /// pivoting.
+    ///
+    /// At each step the row with the largest entry in the pivot column is
+    /// swapped into place, which keeps the multipliers in *L* at most one in
+    /// magnitude. A singular matrix still has a decomposition, but
+    /// `LuDecomposition::is_singular` reports it and `solve` and `inverse`
+    /// fail.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if the matrix is not square.
+    pub fn lu(&self) -> Result<LuDecomposition, Error> {
+        self.check_square()?;
+
+        let n = self.rows;
+        let mut u = self.clone();
+        let mut l: Matrix<f64> = Matrix::new(n, n);
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+        let tolerance = self.tolerance();
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|&a, &b| u.data[a * n + k].abs().total_cmp(&u.data[b * n + k].abs()))
+                .unwrap_or(k);
+            if pivot_row != k {
+                // Only the first `k` columns of L are filled in, so swapping
+                // whole rows keeps it consistent with U.
+                u.swap_rows(k, pivot_row)?;
+                l.swap_rows(k, pivot_row)?;
+                permutation.swap(k, pivot_row);
+                swaps += 1;
+            }
+
+            let pivot = u.data[k * n + k];
+            if pivot.abs() <= tolerance {
+                continue;
+            }
+            for i in k + 1..n {
+                let factor = u.data[i * n + k] / pivot;
+                l.data[i * n + k] = factor;
+                u.data[i * n + k] = 0.0;
+                for j in k + 1..n {
+                    u.data[i * n + j] -= factor * u.data[k * n + j];
+                }
+            }
+        }
+
+        for k in 0..n {
+            l.data[k * n + k] = 1.0;
+        }
+        Ok(LuDecomposition { l, u, permutation, swaps, tolerance })
+    }
+
+    /// Computes the determinant of a square matrix using its LU
+    /// decomposition.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let a = matrix![[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]];
+    /// assert!((a.determinant().unwrap() - 6.0).abs() < 1e-12);
+    /// # }
+    /// ```
+    pub fn determinant(&self) -> Result<f64, Error> {
+        Ok(self.lu()?.determinant())
+    }
+
+    /// Computes the inverse of a square matrix.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix is not square
+    /// - `Error::Singular`: If the matrix is singular
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::error::Error;
+    /// # fn main() {
+    /// let a = matrix![[4.0, 7.0], [2.0, 6.0]];
+    /// let inverse = a.inverse().unwrap();
+    /// assert!((inverse.get(0, 0).unwrap() - 0.6).abs() < 1e-12);
+    ///
+    /// let singular = matrix![[1.0, 2.0], [2.0, 4.0]];
+    /// assert_eq!(singular.inverse(), Err(Error::Singular));
+    /// # }
+    /// ```
+    pub fn inverse(&self) -> Result<Matrix<f64>, Error> {
+        self.lu()?.inverse()
+    }
+
+    /// Solves the linear system *AX = B* for *X*, where *A* is this square
+    /// matrix. Each column of `b` is a separate right-hand side.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix is not square, or `b`
+    ///   does not have as many rows
+    /// - `Error::Singular`: If the matrix is singular
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// // 2x + y = 5, x + 3y = 10
+    /// let a = matrix![[2.0, 1.0], [1.0, 3.0]];
+    /// let b = matrix![[5.0], [10.0]];
+    /// let x = a.solve(&b).unwrap();
+    ///
+    /// assert!((x.get(0, 0).unwrap() - 1.0).abs() < 1e-12);
+    /// assert!((x.get(1, 0).unwrap() - 3.0).abs() < 1e-12);
+    /// # }
+    /// ```
+    pub fn solve(&self, b: &Matrix<f64>) -> Result<Matrix<f64>, Error> {
+        self.lu()?.solve(b)
+    }
+
+    /// Computes the rank of the matrix, the number of linearly independent
+    /// rows, by Gaussian elimination with partial pivoting.
+    ///
+    /// Entries smaller than a tolerance scaled to the size and magnitude of
+    /// the matrix are treated as zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let a = matrix![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]];
+    /// assert_eq!(a.rank(), 2);
+    /// # }
+    /// ```
+    pub fn rank(&self) -> usize {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut a = self.clone();
+        let tolerance = self.tolerance();
+        let mut rank = 0;
+
+        for col in 0..cols {
+            if rank == rows {
+                break;
+            }
+            let pivot_row = (rank..rows)
+                .max_by(|&x, &y| a.data[x * cols + col].abs().total_cmp(&a.data[y * cols + col].abs()))
+                .unwrap_or(rank);
+            let pivot = a.data[pivot_row * cols + col];
+            if pivot.abs() <= tolerance {
+                continue;
+            }
+
+            // Swapping rows of a valid matrix within its bounds cannot fail.
+            let _ = a.swap_rows(rank, pivot_row);
+            for i in rank + 1..rows {
+                let factor = a.data[i * cols + col] / pivot;
+                for j in col..cols {
+                    a.data[i * cols + j] -= factor * a.data[rank * cols + j];
+                }
+            }
+            rank += 1;
+        }
+        rank
+    }
+
+    /// Gets the magnitude below which a pivot is treated as zero, scaled to
+    /// the size and largest entry of the matrix.
+    pub(super) fn tolerance(&self) -> f64 {
+        let max = self.data.iter().fold(0.0_f64, |max, x| max.max(x.abs()));
+        f64::EPSILON * self.rows.max(self.cols) as f64 * max
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Checks that the matrix is square.
+    pub(super) fn check_square(&self) -> Result<(), Error> {
+        if self.rows != self.cols {
+            return Err(Error::DimensionMismatch {
+                expected: (self.rows, self.rows),
+                found: (self.rows, self.cols),
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+use num_traits::Num;
+
+use super::Matrix;
+use crate::error::Error;
+
+/// Selects the algorithm used by `Matrix::multiply`.
+///
+/// All strategies compute the same product; they differ only in how they
+/// traverse the row-major data, which matters a great deal for large
+/// matrices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MulStrategy {
+    /// The textbook *i-j-k* triple loop, computing each result element as a
+    /// dot product. The inner loop walks down a column of the right-hand
+    /// matrix, so it strides through memory and misses the cache often.
+    Naive,
+
+    /// The triple loop reordered to *i-k-j*, so the inner loop walks along a
+    /// row of both the right-hand matrix and the result. This is the
+    /// default strategy.
+    Ikj,
+
+    /// The *i-k-j* loop applied to `tile` *x* `tile` blocks at a time, so
+    /// each block of the operands stays in cache while it is reused. A tile
+    /// of zero is treated as one.
+    Tiled { tile: usize },
+
+    /// Strassen's algorithm, which multiplies two *n x n* matrices with seven
+    /// half-size products instead of eight, for O(*n*<sup>2.81</sup>) work.
+    /// All three dimensions are halved together until the smallest reaches
+    /// `cutoff`, and those sub-problems fall back to `Ikj`.
+    ///
+    /// Each dimension is padded with zeros separately, to the next multiple
+    /// of 2<sup>*d*</sup> for a recursion depth of *d*, so the padded copies
+    /// of the operands are less than 2<sup>*d*</sup> rows or columns larger
+    /// than the originals. The temporaries used by the recursion add a small
+    /// constant multiple of that. A shape far from square, whose smallest
+    /// dimension is at most `cutoff`, is not padded and uses `Ikj` directly.
+    ///
+    /// The sums and differences of sub-matrices that Strassen forms can
+    /// overflow even when the product itself fits, for example a negative
+    /// difference of unsigned values. Integer types therefore use wrapping
+    /// arithmetic throughout, which still gives the exact product whenever
+    /// it is representable.
+    Strassen { cutoff: usize },
+}
+
+impl Default for MulStrategy {
+    /// Returns `MulStrategy::Ikj`.
+    fn default() -> MulStrategy {
+        MulStrategy::Ikj
+    }
+}
+
+/// A number with the wrapping arithmetic used by `MulStrategy::Strassen`.
+///
+/// Integer types wrap around on overflow, while floating-point types use
+/// their ordinary operators, as they cannot overflow in the same way.
+pub trait WrappingNum: Num + Copy {
+    /// Adds `rhs`, wrapping around on overflow.
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    /// Subtracts `rhs`, wrapping around on overflow.
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    /// Multiplies by `rhs`, wrapping around on overflow.
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_wrapping_num {
+    (wrapping: $($t:ty)*) => {$(
+        impl WrappingNum for $t {
+            fn wrapping_add(self, rhs: $t) -> $t {
+                <$t>::wrapping_add(self, rhs)
+            }
+
+            fn wrapping_sub(self, rhs: $t) -> $t {
+                <$t>::wrapping_sub(self, rhs)
+            }
+
+            fn wrapping_mul(self, rhs: $t) -> $t {
+                <$t>::wrapping_mul(self, rhs)
+            }
+        }
+    )*};
+    (float: $($t:ty)*) => {$(
+        impl WrappingNum for $t {
+            fn wrapping_add(self, rhs: $t) -> $t {
+                self + rhs
+            }
+
+            fn wrapping_sub(self, rhs: $t) -> $t {
+                self - rhs
+            }
+
+            fn wrapping_mul(self, rhs: $t) -> $t {
+                self * rhs
+            }
+        }
+    )*};
+}
+
+impl_wrapping_num!(wrapping: i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+impl_wrapping_num!(float: f32 f64);
+
+impl<T: WrappingNum> Matrix<T> {
+    /// Multiplies this *m x n* matrix by an *n x p* matrix using the given
+    /// `strategy`, giving an *m x p* matrix.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `rhs` does not have as many rows
+    /// as this matrix has columns.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// let a: Matrix<i64> = Matrix::from_iter(5, 7, 0..);
+    /// let b: Matrix<i64> = Matrix::from_iter(7, 3, 0..);
+    ///
+    /// let expected = a.multiply(&b, MulStrategy::Naive).unwrap();
+    /// assert_eq!(a.multiply(&b, MulStrategy::Tiled { tile: 2 }).unwrap(), expected);
+    /// assert_eq!(a.multiply(&b, MulStrategy::Strassen { cutoff: 2 }).unwrap(), expected);
+    ///
+    /// let c: Matrix<u32> = Matrix::from_iter(4, 4, 0..);
+    /// let expected = c.multiply(&c, MulStrategy::Ikj).unwrap();
+    /// assert_eq!(c.multiply(&c, MulStrategy::Strassen { cutoff: 1 }).unwrap(), expected);
+    /// ```
+    pub fn multiply(&self, rhs: &Matrix<T>, strategy: MulStrategy) -> Result<Matrix<T>, Error> {
+        if self.cols != rhs.rows {
+            return Err(Error::DimensionMismatch {
+                expected: (self.cols, rhs.cols),
+                found: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let (m, n, p) = (self.rows, self.cols, rhs.cols);
+        let data = match strategy {
+            MulStrategy::Naive => naive(&self.data, &rhs.data, m, n, p),
+            MulStrategy::Ikj => ikj(&self.data, &rhs.data, m, n, p),
+            MulStrategy::Tiled { tile } => tiled(&self.data, &rhs.data, m, n, p, tile.max(1)),
+            MulStrategy::Strassen { cutoff } => {
+                let mut depth = 0;
+                while m.min(n).min(p) >> depth > cutoff.max(1) {
+                    depth += 1;
+                }
+
+                let round_up = |size: usize| size.div_ceil(1 << depth) << depth;
+                let (m_pad, n_pad, p_pad) = (round_up(m), round_up(n), round_up(p));
+                let a = pad(&self.data, m, n, m_pad, n_pad);
+                let b = pad(&rhs.data, n, p, n_pad, p_pad);
+                let c = strassen(&a, &b, m_pad, n_pad, p_pad, depth);
+                (0..m).flat_map(|i| c[i * p_pad..i * p_pad + p].iter().copied()).collect()
+            }
+        };
+
+        Ok(Matrix { rows: m, cols: p, data })
+    }
+}
+
+// The helpers below work on row-major slices, multiplying an m x n matrix
+// `a` by an n x p matrix `b`.
+
+fn naive<T: Num + Copy>(a: &[T], b: &[T], m: usize, n: usize, p: usize) -> Vec<T> {
+    let mut c = Vec::with_capacity(m * p);
+    for i in 0..m {
+        for j in 0..p {
+            c.push((0..n).fold(T::zero(), |sum, k| sum + a[i * n + k] * b[k * p + j]));
+        }
+    }
+    c
+}
+
+fn ikj<T: Num + Copy>(a: &[T], b: &[T], m: usize, n: usize, p: usize) -> Vec<T> {
+    let mut c = vec![T::zero(); m * p];
+    for i in 0..m {
+        let row = &mut c[i * p..(i + 1) * p];
+        for k in 0..n {
+            let a_ik = a[i * n + k];
+            for (c_ij, &b_kj) in row.iter_mut().zip(&b[k * p..(k + 1) * p]) {
+                *c_ij = *c_ij + a_ik * b_kj;
+            }
+        }
+    }
+    c
+}
+
+fn tiled<T: Num + Copy>(a: &[T], b: &[T], m: usize, n: usize, p: usize, tile: usize) -> Vec<T> {
+    let mut c = vec![T::zero(); m * p];
+    for i0 in (0..m).step_by(tile) {
+        for k0 in (0..n).step_by(tile) {
+            for j0 in (0..p).step_by(tile) {
+                let j1 = (j0 + tile).min(p);
+                for i in i0..(i0 + tile).min(m) {
+                    for k in k0..(k0 + tile).min(n) {
+                        let a_ik = a[i * n + k];
+                        for j in j0..j1 {
+                            c[i * p + j] = c[i * p + j] + a_ik * b[k * p + j];
+                        }
+                    }
+                }
+            }
+        }
+    }
+    c
+}
+
+/// Copies a `rows` x `cols` matrix into the top-left corner of a
+/// `pad_rows` x `pad_cols` matrix of zeros.
+fn pad<T: Num + Copy>(data: &[T], rows: usize, cols: usize, pad_rows: usize, pad_cols: usize) -> Vec<T> {
+    if (rows, cols) == (pad_rows, pad_cols) {
+        return data.to_vec();
+    }
+
+    let mut padded = vec![T::zero(); pad_rows * pad_cols];
+    for i in 0..rows {
+        padded[i * pad_cols..i * pad_cols + cols].copy_from_slice(&data[i * cols..(i + 1) * cols]);
+    }
+    padded
+}
+
+/// Multiplies an m x n matrix by an n x p matrix, halving every dimension
+/// `depth` times. Each dimension must be a multiple of 2<sup>`depth`</sup>.
+///
+/// All arithmetic wraps, including the *i-k-j* loop at the base of the
+/// recursion, as the intermediate values may overflow.
+fn strassen<T: WrappingNum>(a: &[T], b: &[T], m: usize, n: usize, p: usize, depth: usize) -> Vec<T> {
+    if depth == 0 {
+        let mut c = vec![T::zero(); m * p];
+        for i in 0..m {
+            let row = &mut c[i * p..(i + 1) * p];
+            for k in 0..n {
+                let a_ik = a[i * n + k];
+                for (c_ij, &b_kj) in row.iter_mut().zip(&b[k * p..(k + 1) * p]) {
+                    *c_ij = c_ij.wrapping_add(a_ik.wrapping_mul(b_kj));
+                }
+            }
+        }
+        return c;
+    }
+
+    let [a11, a12, a21, a22] = split(a, m, n);
+    let [b11, b12, b21, b22] = split(b, n, p);
+    let add = |x: &[T], y: &[T]| -> Vec<T> { x.iter().zip(y).map(|(&x, &y)| x.wrapping_add(y)).collect() };
+    let sub = |x: &[T], y: &[T]| -> Vec<T> { x.iter().zip(y).map(|(&x, &y)| x.wrapping_sub(y)).collect() };
+    let mul = |x: &[T], y: &[T]| strassen(x, y, m / 2, n / 2, p / 2, depth - 1);
+
+    let m1 = mul(&add(&a11, &a22), &add(&b11, &b22));
+    let m2 = mul(&add(&a21, &a22), &b11);
+    let m3 = mul(&a11, &sub(&b12, &b22));
+    let m4 = mul(&a22, &sub(&b21, &b11));
+    let m5 = mul(&add(&a11, &a12), &b22);
+    let m6 = mul(&sub(&a21, &a11), &add(&b11, &b12));
+    let m7 = mul(&sub(&a12, &a22), &add(&b21, &b22));
+
+    let c11 = add(&sub(&add(&m1, &m4), &m5), &m7);
+    let c12 = add(&m3, &m5);
+    let c21 = add(&m2, &m4);
+    let c22 = add(&add(&sub(&m1, &m2), &m3), &m6);
+    join([&c11, &c12, &c21, &c22], m / 2, p / 2)
+}
+
+/// Splits a `rows` x `cols` matrix into its four quadrants, in row-major
+/// order.
+fn split<T: Copy>(data: &[T], rows: usize, cols: usize) -> [Vec<T>; 4] {
+    let (half_rows, half_cols) = (rows / 2, cols / 2);
+    let quadrant = |row0: usize, col0: usize| -> Vec<T> {
+        (row0..row0 + half_rows)
+            .flat_map(|i| data[i * cols + col0..i * cols + col0 + half_cols].iter().copied())
+            .collect()
+    };
+    [quadrant(0, 0), quadrant(0, half_cols), quadrant(half_rows, 0), quadrant(half_rows, half_cols)]
+}
+
+/// Joins four `rows` x `cols` quadrants, in row-major order, into one matrix.
+fn join<T: Copy>(quadrants: [&[T]; 4], rows: usize, cols: usize) -> Vec<T> {
+    let mut data = Vec::with_capacity(4 * rows * cols);
+    for (left, right) in [(0, 1), (2, 3)] {
+        for i in 0..rows {
+            data.extend_from_slice(&quadrants[left][i * cols..(i + 1) * cols]);
+            data.extend_from_slice(&quadrants[right][i * cols..(i + 1) * cols]);
+        }
+    }
+    data
+}
@@ -0,0 +1,245 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::Num;
+
+use super::{Matrix, MulStrategy, WrappingNum};
+use crate::error::Error;
+
+impl<T: Num + Copy> Matrix<T> {
+    /// Adds two matrices element by element.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if the matrices are not the same
+    /// size.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::error::Error;
+    /// # fn main() {
+    /// let a = matrix![[1, 2], [3, 4]];
+    /// let b = matrix![[10, 20], [30, 40]];
+    /// assert_eq!(a.checked_add(&b).unwrap(), matrix![[11, 22], [33, 44]]);
+    ///
+    /// let c = matrix![[1, 2, 3]];
+    /// assert_eq!(
+    ///     a.checked_add(&c),
+    ///     Err(Error::DimensionMismatch { expected: (2, 2), found: (1, 3) })
+    /// );
+    /// # }
+    /// ```
+    pub fn checked_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+
+    /// Subtracts `rhs` from this matrix element by element.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if the matrices are not the same
+    /// size.
+    pub fn checked_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+
+    /// Multiplies every element of the matrix by `scalar`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// let a = matrix![[1, 2], [3, 4]];
+    /// assert_eq!(a.scale(3), matrix![[3, 6], [9, 12]]);
+    /// # }
+    /// ```
+    pub fn scale(&self, scalar: T) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|&a| a * scalar).collect(),
+        }
+    }
+
+    /// Combines two matrices of the same size element by element.
+    fn zip_with<F: Fn(T, T) -> T>(&self, rhs: &Matrix<T>, func: F) -> Result<Matrix<T>, Error> {
+        self.check_same_size(rhs)?;
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().zip(&rhs.data).map(|(&a, &b)| func(a, b)).collect(),
+        })
+    }
+
+    /// Combines `rhs` into this matrix element by element.
+    fn zip_with_mut<F: Fn(T, T) -> T>(&mut self, rhs: &Matrix<T>, func: F) -> Result<(), Error> {
+        self.check_same_size(rhs)?;
+
+        for (a, &b) in self.data.iter_mut().zip(&rhs.data) {
+            *a = func(*a, b);
+        }
+        Ok(())
+    }
+
+    fn check_same_size(&self, rhs: &Matrix<T>) -> Result<(), Error> {
+        if (self.rows, self.cols) != (rhs.rows, rhs.cols) {
+            return Err(Error::DimensionMismatch {
+                expected: (self.rows, self.cols),
+                found: (rhs.rows, rhs.cols),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<T: WrappingNum> Matrix<T> {
+    /// Multiplies this *m x n* matrix by an *n x p* matrix, giving an
+    /// *m x p* matrix.
+    ///
+    /// Uses the default `MulStrategy`; call `multiply` to choose another.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `rhs` does not have as many rows
+    /// as this matrix has columns.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::error::Error;
+    /// # fn main() {
+    /// let a = matrix![[1, 2, 3], [4, 5, 6]];
+    /// let b = matrix![[1, 0], [0, 1], [1, 1]];
+    /// assert_eq!(a.checked_mul(&b).unwrap(), matrix![[4, 5], [10, 11]]);
+    ///
+    /// assert_eq!(
+    ///     a.checked_mul(&a),
+    ///     Err(Error::DimensionMismatch { expected: (3, 3), found: (2, 3) })
+    /// );
+    /// # }
+    /// ```
+    pub fn checked_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        self.multiply(rhs, MulStrategy::default())
+    }
+}
+
+/// Implements a binary operator for every combination of owned and borrowed
+/// operands by delegating to a checked method, panicking on mismatched
+/// dimensions.
+macro_rules! impl_binary_op {
+    ($Op:ident, $op:ident, $checked:ident, $($Bound:tt)+) => {
+        impl<T: $($Bound)+> $Op<&Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $op(self, rhs: &Matrix<T>) -> Matrix<T> {
+                self.$checked(rhs).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl<T: $($Bound)+> $Op<Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $op(self, rhs: Matrix<T>) -> Matrix<T> {
+                (&self).$op(&rhs)
+            }
+        }
+
+        impl<T: $($Bound)+> $Op<&Matrix<T>> for Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $op(self, rhs: &Matrix<T>) -> Matrix<T> {
+                (&self).$op(rhs)
+            }
+        }
+
+        impl<T: $($Bound)+> $Op<Matrix<T>> for &Matrix<T> {
+            type Output = Matrix<T>;
+
+            fn $op(self, rhs: Matrix<T>) -> Matrix<T> {
+                self.$op(&rhs)
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, checked_add, Num + Copy);
+impl_binary_op!(Sub, sub, checked_sub, Num + Copy);
+impl_binary_op!(Mul, mul, checked_mul, WrappingNum);
+
+/// Implements an in-place element-wise operator for owned and borrowed
+/// right-hand sides, panicking on mismatched dimensions.
+macro_rules! impl_assign_op {
+    ($Op:ident, $op:ident, $func:expr) => {
+        impl<T: Num + Copy> $Op<&Matrix<T>> for Matrix<T> {
+            fn $op(&mut self, rhs: &Matrix<T>) {
+                self.zip_with_mut(rhs, $func).unwrap_or_else(|e| panic!("{}", e));
+            }
+        }
+
+        impl<T: Num + Copy> $Op<Matrix<T>> for Matrix<T> {
+            fn $op(&mut self, rhs: Matrix<T>) {
+                self.$op(&rhs);
+            }
+        }
+    };
+}
+
+impl_assign_op!(AddAssign, add_assign, |a, b| a + b);
+impl_assign_op!(SubAssign, sub_assign, |a, b| a - b);
+
+impl<T: WrappingNum> MulAssign<&Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<T: WrappingNum> MulAssign<Matrix<T>> for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<T: Num + Copy> Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(mut self, scalar: T) -> Matrix<T> {
+        self *= scalar;
+        self
+    }
+}
+
+impl<T: Num + Copy> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, scalar: T) -> Matrix<T> {
+        self.scale(scalar)
+    }
+}
+
+impl<T: Num + Copy> MulAssign<T> for Matrix<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        for a in self.data.iter_mut() {
+            *a = *a * scalar;
+        }
+    }
+}
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Matrix<T> {
+        for a in self.data.iter_mut() {
+            *a = -*a;
+        }
+        self
+    }
+}
+
+impl<T: Num + Copy + Neg<Output = T>> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        -self.clone()
+    }
+}
@@ -0,0 +1,292 @@
+use super::Matrix;
+use crate::error::Error;
+
+/// Selects the algorithm used by `Matrix::qr`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum QrMethod {
+    /// Householder reflections, which zero each column below the diagonal
+    /// with an orthogonal reflection. *Q* stays orthogonal to working
+    /// precision, even for ill-conditioned matrices. This is the default.
+    #[default]
+    Householder,
+
+    /// Modified Gram–Schmidt, which orthogonalises the columns one at a
+    /// time, removing each new direction from the remaining columns straight
+    /// away. It is simpler and builds *Q* directly, but *Q* loses
+    /// orthogonality in proportion to the condition number of the matrix.
+    GramSchmidt,
+}
+
+/// The thin QR decomposition of an *m x n* matrix *A* with *m* ≥ *n*, such
+/// that *A = QR*.
+///
+/// *Q* is *m x n* with orthonormal columns and *R* is *n x n* upper
+/// triangular. Created by `Matrix::qr`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let a = matrix![[3.0, 1.0], [4.0, 2.0], [0.0, 2.0]];
+/// let qr = a.qr(QrMethod::Householder).unwrap();
+///
+/// assert_eq!((qr.q().rows(), qr.q().cols()), (3, 2));
+/// assert!((qr.r().get(0, 0).unwrap().abs() - 5.0).abs() < 1e-12);
+/// assert_eq!(qr.r().get(1, 0).unwrap(), &0.0);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrDecomposition {
+    q: Matrix<f64>,
+    r: Matrix<f64>,
+    tolerance: f64,
+}
+
+impl QrDecomposition {
+    /// Gets the factor *Q*, whose columns are orthonormal.
+    pub fn q(&self) -> &Matrix<f64> {
+        &self.q
+    }
+
+    /// Gets the upper triangular factor *R*.
+    pub fn r(&self) -> &Matrix<f64> {
+        &self.r
+    }
+
+    /// Finds the *X* minimising the residual ‖*AX - B*‖ by solving
+    /// *RX = Q*<sup>T</sup>*B* with back substitution. Each column of `b` is
+    /// a separate right-hand side.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If `b` does not have as many rows as *A*
+    /// - `Error::Singular`: If the columns of *A* are linearly dependent
+    pub fn solve(&self, b: &Matrix<f64>) -> Result<Matrix<f64>, Error> {
+        let (m, n, k) = (self.q.rows, self.q.cols, b.cols);
+        if b.rows != m {
+            return Err(Error::DimensionMismatch { expected: (m, k), found: (b.rows, k) });
+        }
+        if (0..n).any(|i| self.r.data[i * n + i].abs() <= self.tolerance) {
+            return Err(Error::Singular);
+        }
+
+        // Q^T B, as an n x k matrix.
+        let mut x = vec![0.0; n * k];
+        for i in 0..m {
+            for j in 0..n {
+                let q_ij = self.q.data[i * n + j];
+                for c in 0..k {
+                    x[j * k + c] += q_ij * b.data[i * k + c];
+                }
+            }
+        }
+
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let r_ij = self.r.data[i * n + j];
+                for c in 0..k {
+                    x[i * k + c] -= r_ij * x[j * k + c];
+                }
+            }
+            let pivot = self.r.data[i * n + i];
+            for c in 0..k {
+                x[i * k + c] /= pivot;
+            }
+        }
+
+        Ok(Matrix { rows: n, cols: k, data: x })
+    }
+}
+
+/// The solution of a linear least-squares problem, created by
+/// `Matrix::least_squares`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeastSquares {
+    solution: Matrix<f64>,
+    residual: Matrix<f64>,
+    residual_norms: Vec<f64>,
+}
+
+impl LeastSquares {
+    /// Gets the solution *X*, with one column per right-hand side.
+    pub fn solution(&self) -> &Matrix<f64> {
+        &self.solution
+    }
+
+    /// Gets the residual *B - AX*.
+    pub fn residual(&self) -> &Matrix<f64> {
+        &self.residual
+    }
+
+    /// Gets the Euclidean norm of each column of the residual.
+    pub fn residual_norms(&self) -> &[f64] {
+        &self.residual_norms
+    }
+}
+
+impl Matrix<f64> {
+    /// Computes the thin QR decomposition of an *m x n* matrix with
+    /// *m* ≥ *n*, using the given `method`.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix has fewer rows than
+    ///   columns
+    /// - `Error::Singular`: If `method` is `QrMethod::GramSchmidt` and the
+    ///   columns are linearly dependent. Householder QR still succeeds, with
+    ///   a zero on the diagonal of *R*.
+    pub fn qr(&self, method: QrMethod) -> Result<QrDecomposition, Error> {
+        if self.rows < self.cols {
+            return Err(Error::DimensionMismatch {
+                expected: (self.cols, self.cols),
+                found: (self.rows, self.cols),
+            });
+        }
+
+        let tolerance = self.tolerance();
+        let (q, r) = match method {
+            QrMethod::Householder => self.householder(),
+            QrMethod::GramSchmidt => self.gram_schmidt(tolerance)?,
+        };
+        Ok(QrDecomposition { q, r, tolerance })
+    }
+
+    /// Solves the linear least-squares problem, finding the *X* that
+    /// minimises ‖*AX - B*‖ for this *m x n* matrix *A* with *m* ≥ *n*. Each
+    /// column of `b` is a separate right-hand side.
+    ///
+    /// Uses Householder QR; see `least_squares_with` to choose the method.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the matrix has fewer rows than
+    ///   columns, or `b` does not have as many rows
+    /// - `Error::Singular`: If the columns of the matrix are linearly
+    ///   dependent
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # fn main() {
+    /// // Fit y = c0 + c1 * x through (0, 1), (1, 3), (2, 5), (3, 7.5)
+    /// let a = matrix![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]];
+    /// let y = matrix![[1.0], [3.0], [5.0], [7.5]];
+    /// let fit = a.least_squares(&y).unwrap();
+    ///
+    /// assert!((fit.solution().get(0, 0).unwrap() - 0.9).abs() < 1e-12);
+    /// assert!((fit.solution().get(1, 0).unwrap() - 2.15).abs() < 1e-12);
+    /// assert!(fit.residual_norms()[0] > 0.0);
+    /// # }
+    /// ```
+    pub fn least_squares(&self, b: &Matrix<f64>) -> Result<LeastSquares, Error> {
+        self.least_squares_with(b, QrMethod::default())
+    }
+
+    /// Solves the linear least-squares problem like `least_squares`, using
+    /// the given QR `method`.
+    ///
+    /// # Errors
+    /// As for `least_squares`.
+    pub fn least_squares_with(&self, b: &Matrix<f64>, method: QrMethod) -> Result<LeastSquares, Error> {
+        let solution = self.qr(method)?.solve(b)?;
+        let residual = b.checked_sub(&self.checked_mul(&solution)?)?;
+        let k = residual.cols;
+        let residual_norms = (0..k)
+            .map(|c| (0..residual.rows).map(|i| residual.data[i * k + c].powi(2)).sum::<f64>().sqrt())
+            .collect();
+
+        Ok(LeastSquares { solution, residual, residual_norms })
+    }
+
+    fn householder(&self) -> (Matrix<f64>, Matrix<f64>) {
+        let (m, n) = (self.rows, self.cols);
+        let mut r = self.data.clone();
+        let mut reflectors: Vec<Vec<f64>> = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let v = householder_vector((k..m).map(|i| r[i * n + k]).collect());
+            reflect(&mut r, n, k, k, &v);
+            reflectors.push(v);
+        }
+
+        // Q is the product of the reflectors applied to the first n columns
+        // of the identity.
+        let mut q = vec![0.0; m * n];
+        for i in 0..n {
+            q[i * n + i] = 1.0;
+        }
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            reflect(&mut q, n, k, 0, v);
+        }
+
+        let mut r_thin = vec![0.0; n * n];
+        for i in 0..n {
+            r_thin[i * n + i..(i + 1) * n].copy_from_slice(&r[i * n + i..(i + 1) * n]);
+        }
+        (Matrix { rows: m, cols: n, data: q }, Matrix { rows: n, cols: n, data: r_thin })
+    }
+
+    fn gram_schmidt(&self, tolerance: f64) -> Result<(Matrix<f64>, Matrix<f64>), Error> {
+        let (m, n) = (self.rows, self.cols);
+        let mut q = self.data.clone();
+        let mut r = vec![0.0; n * n];
+
+        for k in 0..n {
+            let norm = (0..m).map(|i| q[i * n + k].powi(2)).sum::<f64>().sqrt();
+            if norm <= tolerance {
+                return Err(Error::Singular);
+            }
+            r[k * n + k] = norm;
+            for i in 0..m {
+                q[i * n + k] /= norm;
+            }
+
+            for j in k + 1..n {
+                let dot: f64 = (0..m).map(|i| q[i * n + k] * q[i * n + j]).sum();
+                r[k * n + j] = dot;
+                for i in 0..m {
+                    q[i * n + j] -= dot * q[i * n + k];
+                }
+            }
+        }
+
+        Ok((Matrix { rows: m, cols: n, data: q }, Matrix { rows: n, cols: n, data: r }))
+    }
+}
+
+/// Finds the unit vector *v* whose reflection *I - 2vv*<sup>T</sup> maps `x`
+/// onto a multiple of the first unit vector, choosing the sign that avoids
+/// cancellation. Returns a zero vector if `x` is zero.
+pub(super) fn householder_vector(mut x: Vec<f64>) -> Vec<f64> {
+    let norm = x.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if x.is_empty() || norm == 0.0 {
+        return x;
+    }
+
+    x[0] += if x[0] >= 0.0 { norm } else { -norm };
+    let v_norm = x.iter().map(|x| x * x).sum::<f64>().sqrt();
+    x.iter_mut().for_each(|x| *x /= v_norm);
+    x
+}
+
+/// Applies the Householder reflection *I - 2vv*<sup>T</sup> from the left to
+/// rows `k..` and columns `col0..` of a row-major matrix with `n` columns.
+pub(super) fn reflect(data: &mut [f64], n: usize, k: usize, col0: usize, v: &[f64]) {
+    for j in col0..n {
+        let dot: f64 = v.iter().enumerate().map(|(i, v_i)| v_i * data[(k + i) * n + j]).sum();
+        for (i, v_i) in v.iter().enumerate() {
+            data[(k + i) * n + j] -= 2.0 * v_i * dot;
+        }
+    }
+}
+
+/// Applies the Householder reflection *I - 2vv*<sup>T</sup> from the right to
+/// columns `k..` and rows `row0..` of a row-major matrix with `n` columns.
+pub(super) fn reflect_right(data: &mut [f64], n: usize, k: usize, row0: usize, v: &[f64]) {
+    for row in data.chunks_exact_mut(n).skip(row0) {
+        let dot: f64 = v.iter().enumerate().map(|(j, v_j)| v_j * row[k + j]).sum();
+        for (j, v_j) in v.iter().enumerate() {
+            row[k + j] -= 2.0 * v_j * dot;
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use super::{IsMatrix, Matrix};
+use crate::error::Error;
+
+/// Maximum number of implicit QR steps spent isolating each singular value.
+const MAX_ITERATIONS: usize = 75;
+
+/// The thin singular value decomposition of an *m x n* matrix *A*, such
+/// that *A = UΣV*<sup>T</sup>.
+///
+/// With *k = min(m, n)*, *U* is *m x k* and *V* is *n x k*, both with
+/// orthonormal columns, and *Σ* is the diagonal of the *k* nonnegative
+/// singular values, sorted in descending order. Created by `Matrix::svd`.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let a = matrix![[3.0, 0.0], [0.0, -4.0], [0.0, 0.0]];
+/// let svd = a.svd().unwrap();
+///
+/// assert!((svd.singular_values()[0] - 4.0).abs() < 1e-12);
+/// assert!((svd.singular_values()[1] - 3.0).abs() < 1e-12);
+/// assert!((svd.condition_number() - 4.0 / 3.0).abs() < 1e-12);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SingularValueDecomposition {
+    u: Matrix<f64>,
+    singular_values: Vec<f64>,
+    v: Matrix<f64>,
+}
+
+impl SingularValueDecomposition {
+    /// Gets the *m x k* matrix of left singular vectors.
+    pub fn u(&self) -> &Matrix<f64> {
+        &self.u
+    }
+
+    /// Gets the singular values, in descending order.
+    pub fn singular_values(&self) -> &[f64] {
+        &self.singular_values
+    }
+
+    /// Gets the *n x k* matrix of right singular vectors.
+    pub fn v(&self) -> &Matrix<f64> {
+        &self.v
+    }
+
+    /// Gets the 2-norm condition number, the ratio of the largest to the
+    /// smallest singular value.
+    ///
+    /// # Returns
+    /// Returns infinity if the matrix is rank deficient, or NaN if it is
+    /// zero.
+    pub fn condition_number(&self) -> f64 {
+        self.singular_values[0] / self.singular_values[self.singular_values.len() - 1]
+    }
+
+    /// Gets the numerical rank, the number of singular values larger than
+    /// `tolerance`.
+    pub fn rank(&self, tolerance: f64) -> usize {
+        self.singular_values.iter().take_while(|&&s| s > tolerance).count()
+    }
+}
+
+impl Matrix<f64> {
+    /// Computes the thin singular value decomposition by the Golub-Kahan
+    /// method.
+    ///
+    /// Householder reflections reduce the matrix to bidiagonal form, and
+    /// implicitly shifted QR steps, with Givens rotations chasing the bulge
+    /// down the bidiagonal, then drive the superdiagonal to zero. Wide
+    /// matrices are decomposed through their transpose.
+    ///
+    /// # Errors
+    /// - `Error::NotConverged`: If a singular value was not isolated within
+    ///   75 QR steps
+    pub fn svd(&self) -> Result<SingularValueDecomposition, Error> {
+        if self.rows < self.cols {
+            let SingularValueDecomposition { u, singular_values, v } = self.transposed().svd()?;
+            return Ok(SingularValueDecomposition { u: v, singular_values, v: u });
+        }
+
+        let (m, n) = (self.rows, self.cols);
+        let (mut u, mut s, mut v) = self.bidiagonalize();
+        let mut e = s.split_off(n);
+        golub_kahan(&mut u, &mut s, &mut v, &mut e, m, n)?;
+
+        Ok(SingularValueDecomposition {
+            u: Matrix { rows: m, cols: n, data: u },
+            singular_values: s,
+            v: Matrix { rows: n, cols: n, data: v },
+        })
+    }
+
+    /// Copies the matrix with its rows and columns swapped.
+    fn transposed(&self) -> Matrix<f64> {
+        let transpose = self.transpose();
+        Matrix { rows: transpose.rows, cols: transpose.cols, data: transpose.data.into_iter().copied().collect() }
+    }
+
+    /// Reduces a tall matrix to upper bidiagonal form *B = U*<sup>T</sup>*AV*
+    /// with Householder reflections, after the LINPACK routine `dsvdc`.
+    ///
+    /// # Returns
+    /// Returns the *m x n* matrix *U*, the *n* diagonal entries of *B*
+    /// followed by its *n* superdiagonal entries (the last being zero), and
+    /// the *n x n* matrix *V*, all row-major.
+    fn bidiagonalize(&self) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let (m, n) = (self.rows, self.cols);
+        let mut a = self.data.clone();
+        let mut s = vec![0.0; n];
+        let mut e = vec![0.0; n];
+        let mut u = vec![0.0; m * n];
+        let mut v = vec![0.0; n * n];
+        let mut work = vec![0.0; m];
+
+        // Columns and rows that need a reflection.
+        let nct = (m - 1).min(n);
+        let nrt = n.saturating_sub(2).min(m);
+
+        for k in 0..nct.max(nrt) {
+            if k < nct {
+                // Reflect column k onto the diagonal.
+                s[k] = (k..m).fold(0.0, |norm: f64, i| norm.hypot(a[i * n + k]));
+                if s[k] != 0.0 {
+                    if a[k * n + k] < 0.0 {
+                        s[k] = -s[k];
+                    }
+                    for i in k..m {
+                        a[i * n + k] /= s[k];
+                    }
+                    a[k * n + k] += 1.0;
+                }
+                s[k] = -s[k];
+            }
+
+            for j in k + 1..n {
+                if k < nct && s[k] != 0.0 {
+                    let dot: f64 = (k..m).map(|i| a[i * n + k] * a[i * n + j]).sum();
+                    let t = -dot / a[k * n + k];
+                    for i in k..m {
+                        a[i * n + j] += t * a[i * n + k];
+                    }
+                }
+                e[j] = a[k * n + j];
+            }
+
+            if k < nct {
+                for i in k..m {
+                    u[i * n + k] = a[i * n + k];
+                }
+            }
+
+            if k < nrt {
+                // Reflect row k onto the superdiagonal.
+                e[k] = (k + 1..n).fold(0.0, |norm: f64, i| norm.hypot(e[i]));
+                if e[k] != 0.0 {
+                    if e[k + 1] < 0.0 {
+                        e[k] = -e[k];
+                    }
+                    let scale = e[k];
+                    e[k + 1..n].iter_mut().for_each(|x| *x /= scale);
+                    e[k + 1] += 1.0;
+                }
+                e[k] = -e[k];
+
+                if k + 1 < m && e[k] != 0.0 {
+                    work[k + 1..m].iter_mut().for_each(|x| *x = 0.0);
+                    for j in k + 1..n {
+                        for i in k + 1..m {
+                            work[i] += e[j] * a[i * n + j];
+                        }
+                    }
+                    for j in k + 1..n {
+                        let t = -e[j] / e[k + 1];
+                        for i in k + 1..m {
+                            a[i * n + j] += t * work[i];
+                        }
+                    }
+                }
+
+                for i in k + 1..n {
+                    v[i * n + k] = e[i];
+                }
+            }
+        }
+
+        // Set up the final bidiagonal entries.
+        if nct < n {
+            s[nct] = a[nct * n + nct];
+        }
+        if nrt + 1 < n {
+            e[nrt] = a[nrt * n + n - 1];
+        }
+        e[n - 1] = 0.0;
+
+        // Accumulate U from the stored column reflections.
+        for j in nct..n {
+            u[j * n + j] = 1.0;
+        }
+        for k in (0..nct).rev() {
+            if s[k] != 0.0 {
+                for j in k + 1..n {
+                    let dot: f64 = (k..m).map(|i| u[i * n + k] * u[i * n + j]).sum();
+                    let t = -dot / u[k * n + k];
+                    for i in k..m {
+                        u[i * n + j] += t * u[i * n + k];
+                    }
+                }
+                for i in k..m {
+                    u[i * n + k] = -u[i * n + k];
+                }
+                u[k * n + k] += 1.0;
+                for i in 0..k {
+                    u[i * n + k] = 0.0;
+                }
+            } else {
+                for i in 0..m {
+                    u[i * n + k] = 0.0;
+                }
+                u[k * n + k] = 1.0;
+            }
+        }
+
+        // Accumulate V from the stored row reflections.
+        for k in (0..n).rev() {
+            if k < nrt && e[k] != 0.0 {
+                for j in k + 1..n {
+                    let dot: f64 = (k + 1..n).map(|i| v[i * n + k] * v[i * n + j]).sum();
+                    let t = -dot / v[(k + 1) * n + k];
+                    for i in k + 1..n {
+                        v[i * n + j] += t * v[i * n + k];
+                    }
+                }
+            }
+            for i in 0..n {
+                v[i * n + k] = 0.0;
+            }
+            v[k * n + k] = 1.0;
+        }
+
+        s.extend(e);
+        (u, s, v)
+    }
+}
+
+/// Applies a Givens rotation to columns `p` and `q` of a row-major matrix
+/// with `n` columns, replacing them by `c * p + s * q` and `c * q - s * p`.
+fn rotate(a: &mut [f64], n: usize, p: usize, q: usize, c: f64, s: f64) {
+    for row in a.chunks_exact_mut(n) {
+        let t = c * row[p] + s * row[q];
+        row[q] = c * row[q] - s * row[p];
+        row[p] = t;
+    }
+}
+
+/// Diagonalises the upper bidiagonal matrix with diagonal `s` and
+/// superdiagonal `e` by implicitly shifted QR steps, accumulating the
+/// rotations into the *m x n* matrix `u` and the *n x n* matrix `v`.
+fn golub_kahan(
+    u: &mut [f64],
+    s: &mut [f64],
+    v: &mut [f64],
+    e: &mut [f64],
+    m: usize,
+    n: usize,
+) -> Result<(), Error> {
+    let eps = f64::EPSILON;
+    let tiny = 2.0f64.powi(-966);
+    let mut p = n;
+    let mut iterations = 0;
+
+    while p > 0 {
+        // Find the start k of the unreduced block ending at p - 1, zeroing
+        // a negligible superdiagonal entry that splits it off.
+        let mut k = p - 1;
+        while k > 0 {
+            if e[k - 1].abs() <= tiny + eps * (s[k - 1].abs() + s[k].abs()) {
+                e[k - 1] = 0.0;
+                break;
+            }
+            k -= 1;
+        }
+
+        if k == p - 1 {
+            // s[k] has converged; make it nonnegative and sort it into place.
+            if s[k] <= 0.0 {
+                s[k] = if s[k] < 0.0 { -s[k] } else { 0.0 };
+                v.chunks_exact_mut(n).for_each(|row| row[k] = -row[k]);
+            }
+            while k + 1 < n && s[k] < s[k + 1] {
+                s.swap(k, k + 1);
+                v.chunks_exact_mut(n).for_each(|row| row.swap(k, k + 1));
+                u.chunks_exact_mut(n).for_each(|row| row.swap(k, k + 1));
+                k += 1;
+            }
+            iterations = 0;
+            p -= 1;
+            continue;
+        }
+
+        // Look for a negligible diagonal entry inside the block.
+        let zero = (k..p).rev().find(|&ks| {
+            let t = e[ks].abs() + if ks != k { e[ks - 1].abs() } else { 0.0 };
+            s[ks].abs() <= tiny + eps * t
+        });
+
+        match zero {
+            Some(ks) if ks == p - 1 => {
+                // Deflate a zero at the bottom of the block by chasing
+                // e[p - 2] up the columns.
+                s[ks] = 0.0;
+                let mut f = e[p - 2];
+                e[p - 2] = 0.0;
+                for j in (k..p - 1).rev() {
+                    let t = s[j].hypot(f);
+                    let (cs, sn) = (s[j] / t, f / t);
+                    s[j] = t;
+                    if j != k {
+                        f = -sn * e[j - 1];
+                        e[j - 1] *= cs;
+                    }
+                    rotate(v, n, j, p - 1, cs, sn);
+                }
+            }
+            Some(ks) => {
+                // Split the block at a zero in the middle by chasing e[ks]
+                // along the rows.
+                s[ks] = 0.0;
+                let k = ks + 1;
+                let mut f = e[k - 1];
+                e[k - 1] = 0.0;
+                for j in k..p {
+                    let t = s[j].hypot(f);
+                    let (cs, sn) = (s[j] / t, f / t);
+                    s[j] = t;
+                    f = -sn * e[j];
+                    e[j] *= cs;
+                    rotate(u, n, j, k - 1, cs, sn);
+                }
+            }
+            None => {
+                if iterations == MAX_ITERATIONS {
+                    return Err(Error::NotConverged { iterations });
+                }
+                iterations += 1;
+
+                // Wilkinson shift from the trailing 2 x 2 block of BᵀB.
+                let scale = [s[p - 1], s[p - 2], e[p - 2], s[k], e[k]]
+                    .iter()
+                    .fold(0.0, |max: f64, x| max.max(x.abs()));
+                let (sp, spm1, epm1) = (s[p - 1] / scale, s[p - 2] / scale, e[p - 2] / scale);
+                let (sk, ek) = (s[k] / scale, e[k] / scale);
+                let b = ((spm1 + sp) * (spm1 - sp) + epm1 * epm1) / 2.0;
+                let c = (sp * epm1).powi(2);
+                let mut shift = 0.0;
+                if b != 0.0 || c != 0.0 {
+                    shift = (b * b + c).sqrt();
+                    if b < 0.0 {
+                        shift = -shift;
+                    }
+                    shift = c / (b + shift);
+                }
+
+                // Chase the bulge down the bidiagonal.
+                let mut f = (sk + sp) * (sk - sp) + shift;
+                let mut g = sk * ek;
+                for j in k..p - 1 {
+                    let t = f.hypot(g);
+                    let (cs, sn) = (f / t, g / t);
+                    if j != k {
+                        e[j - 1] = t;
+                    }
+                    f = cs * s[j] + sn * e[j];
+                    e[j] = cs * e[j] - sn * s[j];
+                    g = sn * s[j + 1];
+                    s[j + 1] *= cs;
+                    rotate(v, n, j, j + 1, cs, sn);
+
+                    let t = f.hypot(g);
+                    let (cs, sn) = (f / t, g / t);
+                    s[j] = t;
+                    f = cs * e[j] + sn * s[j + 1];
+                    s[j + 1] = -sn * e[j] + cs * s[j + 1];
+                    g = sn * e[j + 1];
+                    e[j + 1] *= cs;
+                    if j + 1 < m {
+                        rotate(u, n, j, j + 1, cs, sn);
+                    }
+                }
+                e[p - 2] = f;
+            }
+        }
+    }
+
+    Ok(())
+}
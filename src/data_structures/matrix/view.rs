@@ -0,0 +1,646 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::ptr;
+
+use super::{check_index, ColumnIterator, IsMatrix, IsMatrixMut, Matrix, RowIterator};
+use crate::error::Error;
+
+/// A read-only, zero-copy view of part of a `Matrix`.
+///
+/// The value at (`row`, `col`) of the view is read from position
+/// `offset + row * row_stride + col * col_stride` of the matrix's row-major
+/// buffer, so submatrices, transposes and strided views of a matrix, or of
+/// another view, are all found without copying.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let matrix = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+///
+/// let corner = matrix.submatrix(1..3, 1..3).unwrap();
+/// assert_eq!(corner.to_matrix().unwrap(), matrix![[5, 6], [8, 9]]);
+///
+/// let transpose = corner.transpose_view();
+/// assert_eq!(transpose.get(0, 1), Some(&8));
+///
+/// let (left, right) = matrix.view().split_at_col(1).unwrap();
+/// assert_eq!((left.cols(), right.cols()), (1, 2));
+///
+/// let odd = matrix.view().strided(2, 2);
+/// assert_eq!(odd.to_matrix().unwrap(), matrix![[1, 3], [7, 9]]);
+/// # }
+/// ```
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    layout: Layout,
+}
+
+/// A mutable, zero-copy view of part of a `Matrix`.
+///
+/// Views are laid out as for `MatrixView`. `split_at_row_mut` and
+/// `split_at_col_mut` divide a view into two that can be written
+/// independently, for instance from different threads. The halves of a
+/// split can interleave in memory, so a mutable view holds a pointer into
+/// the buffer rather than a slice of it, and its row and column iterators
+/// walk that pointer by the view's strides.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # fn main() {
+/// let mut matrix = matrix![[1, 2, 3], [4, 5, 6]];
+///
+/// let (mut left, mut right) = matrix.split_at_col_mut(1).unwrap();
+/// left.apply_mut(|x| *x *= 10);
+/// right.transpose_view_mut().set(1, 0, 0);
+///
+/// assert_eq!(matrix, matrix![[10, 2, 0], [40, 5, 6]]);
+/// # }
+/// ```
+pub struct MatrixViewMut<'a, T> {
+    ptr: *mut T,
+    layout: Layout,
+    marker: PhantomData<&'a mut T>,
+}
+
+/// Maps the (`row`, `col`) positions of a view to positions in a matrix
+/// buffer.
+///
+/// Views are only derived from a `Matrix` by narrowing, transposing,
+/// striding and splitting, so distinct positions of a view always map to
+/// distinct positions within the buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Layout {
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl Layout {
+    fn of<T>(matrix: &Matrix<T>) -> Layout {
+        Layout { offset: 0, rows: matrix.rows, cols: matrix.cols, row_stride: matrix.cols, col_stride: 1 }
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        self.offset + row * self.row_stride + col * self.col_stride
+    }
+
+    fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    fn indices(self) -> impl Iterator<Item = usize> {
+        (0..self.rows).flat_map(move |row| (0..self.cols).map(move |col| self.index(row, col)))
+    }
+
+    fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Layout, Error> {
+        check_range(&rows, self.rows)?;
+        check_range(&cols, self.cols)?;
+
+        Ok(Layout {
+            offset: self.index(rows.start, cols.start),
+            rows: rows.len(),
+            cols: cols.len(),
+            ..*self
+        })
+    }
+
+    fn transposed(&self) -> Layout {
+        Layout {
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..*self
+        }
+    }
+
+    fn strided(&self, row_step: usize, col_step: usize) -> Layout {
+        assert!(row_step > 0 && col_step > 0);
+
+        Layout {
+            rows: self.rows.div_ceil(row_step),
+            cols: self.cols.div_ceil(col_step),
+            row_stride: self.row_stride * row_step,
+            col_stride: self.col_stride * col_step,
+            ..*self
+        }
+    }
+
+    fn split_at_row(&self, row: usize) -> Result<(Layout, Layout), Error> {
+        check_range(&(0..row), self.rows)?;
+
+        Ok((self.submatrix(0..row, 0..self.cols)?, self.submatrix(row..self.rows, 0..self.cols)?))
+    }
+
+    fn split_at_col(&self, col: usize) -> Result<(Layout, Layout), Error> {
+        check_range(&(0..col), self.cols)?;
+
+        Ok((self.submatrix(0..self.rows, 0..col)?, self.submatrix(0..self.rows, col..self.cols)?))
+    }
+}
+
+/// Checks that `range` lies within a dimension of length `len`.
+fn check_range(range: &Range<usize>, len: usize) -> Result<(), Error> {
+    if range.end > len {
+        Err(Error::OutOfBounds { index: range.end, len })
+    } else if range.start > range.end {
+        Err(Error::OutOfBounds { index: range.start, len: range.end })
+    } else {
+        Ok(())
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Gets a read-only view of the whole matrix.
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView { data: &self.data, layout: Layout::of(self) }
+    }
+
+    /// Gets a mutable view of the whole matrix.
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let layout = Layout::of(self);
+        MatrixViewMut { ptr: self.data.as_mut_ptr(), layout, marker: PhantomData }
+    }
+
+    /// Gets a read-only view of the `rows` and `cols` ranges of the matrix.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either range is outside the matrix.
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixView<'_, T>, Error> {
+        self.view().submatrix(rows, cols)
+    }
+
+    /// Gets a mutable view of the `rows` and `cols` ranges of the matrix.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either range is outside the matrix.
+    pub fn submatrix_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixViewMut<'_, T>, Error> {
+        let layout = Layout::of(self).submatrix(rows, cols)?;
+        Ok(MatrixViewMut { ptr: self.data.as_mut_ptr(), layout, marker: PhantomData })
+    }
+
+    /// Gets a read-only view of the transpose of the matrix, without copying
+    /// or collecting references as `transpose` does.
+    pub fn transpose_view(&self) -> MatrixView<'_, T> {
+        self.view().transpose_view()
+    }
+
+    /// Splits the matrix into read-only views of the rows before `row` and
+    /// the rows from `row` on.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is greater than the number of
+    /// rows.
+    pub fn split_at_row(&self, row: usize) -> Result<(MatrixView<'_, T>, MatrixView<'_, T>), Error> {
+        self.view().split_at_row(row)
+    }
+
+    /// Splits the matrix into read-only views of the columns before `col`
+    /// and the columns from `col` on.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is greater than the number of
+    /// columns.
+    pub fn split_at_col(&self, col: usize) -> Result<(MatrixView<'_, T>, MatrixView<'_, T>), Error> {
+        self.view().split_at_col(col)
+    }
+
+    /// Splits the matrix into disjoint mutable views of the rows before
+    /// `row` and the rows from `row` on.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is greater than the number of
+    /// rows.
+    pub fn split_at_row_mut(&mut self, row: usize) -> Result<(MatrixViewMut<'_, T>, MatrixViewMut<'_, T>), Error> {
+        let (top, bottom) = Layout::of(self).split_at_row(row)?;
+        // SAFETY: the halves are disjoint parts of the borrowed matrix.
+        Ok(unsafe { split(self.data.as_mut_ptr(), top, bottom) })
+    }
+
+    /// Splits the matrix into disjoint mutable views of the columns before
+    /// `col` and the columns from `col` on.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is greater than the number of
+    /// columns.
+    pub fn split_at_col_mut(&mut self, col: usize) -> Result<(MatrixViewMut<'_, T>, MatrixViewMut<'_, T>), Error> {
+        let (left, right) = Layout::of(self).split_at_col(col)?;
+        // SAFETY: the halves are disjoint parts of the borrowed matrix.
+        Ok(unsafe { split(self.data.as_mut_ptr(), left, right) })
+    }
+}
+
+/// Creates two mutable views of the buffer at `ptr`.
+///
+/// # Safety
+/// The layouts must map to disjoint positions of a buffer that is mutably
+/// borrowed for `'a`.
+unsafe fn split<'a, T>(ptr: *mut T, a: Layout, b: Layout) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+    (
+        MatrixViewMut { ptr, layout: a, marker: PhantomData },
+        MatrixViewMut { ptr, layout: b, marker: PhantomData },
+    )
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    fn with_layout(&self, layout: Layout) -> MatrixView<'a, T> {
+        MatrixView { data: self.data, layout }
+    }
+
+    /// Gets the position of the first value of the view in the row-major
+    /// buffer of the matrix it views.
+    pub fn offset(&self) -> usize {
+        self.layout.offset
+    }
+
+    /// Gets the (`row`, `col`) strides: the distances in the matrix buffer
+    /// between neighbouring rows and columns of the view.
+    pub fn strides(&self) -> (usize, usize) {
+        (self.layout.row_stride, self.layout.col_stride)
+    }
+
+    /// Gets a view of the `rows` and `cols` ranges of this view.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either range is outside the view.
+    pub fn submatrix(&self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixView<'a, T>, Error> {
+        Ok(self.with_layout(self.layout.submatrix(rows, cols)?))
+    }
+
+    /// Gets a view of the transpose of this view, by swapping its strides.
+    pub fn transpose_view(&self) -> MatrixView<'a, T> {
+        self.with_layout(self.layout.transposed())
+    }
+
+    /// Gets a view of every `row_step`-th row and `col_step`-th column,
+    /// starting with the first.
+    ///
+    /// # Panics
+    /// Panics if either step is zero.
+    pub fn strided(&self, row_step: usize, col_step: usize) -> MatrixView<'a, T> {
+        self.with_layout(self.layout.strided(row_step, col_step))
+    }
+
+    /// Splits the view into views of the rows before `row` and the rows
+    /// from `row` on.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is greater than the number of
+    /// rows.
+    pub fn split_at_row(&self, row: usize) -> Result<(MatrixView<'a, T>, MatrixView<'a, T>), Error> {
+        let (top, bottom) = self.layout.split_at_row(row)?;
+        Ok((self.with_layout(top), self.with_layout(bottom)))
+    }
+
+    /// Splits the view into views of the columns before `col` and the
+    /// columns from `col` on.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is greater than the number of
+    /// columns.
+    pub fn split_at_col(&self, col: usize) -> Result<(MatrixView<'a, T>, MatrixView<'a, T>), Error> {
+        let (left, right) = self.layout.split_at_col(col)?;
+        Ok((self.with_layout(left), self.with_layout(right)))
+    }
+
+    /// Copies the viewed values into a new `Matrix`.
+    ///
+    /// # Errors
+    /// Returns `Error::ZeroDimension` if the view has no rows or no columns,
+    /// as a `Matrix` cannot be empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::error::Error;
+    /// # fn main() {
+    /// let matrix = matrix![[1, 2], [3, 4]];
+    /// let (left, right) = matrix.split_at_col(2).unwrap();
+    ///
+    /// assert_eq!(left.to_matrix(), Ok(matrix![[1, 2], [3, 4]]));
+    /// assert_eq!(right.to_matrix(), Err(Error::ZeroDimension { rows: 2, cols: 0 }));
+    /// # }
+    /// ```
+    pub fn to_matrix(&self) -> Result<Matrix<T>, Error>
+    where
+        T: Clone,
+    {
+        let data = self.layout.indices().map(|index| self.value(index).clone()).collect();
+        Matrix::try_from_vec(self.layout.rows, self.layout.cols, data)
+    }
+
+    /// Gets the value at `index` of the matrix buffer.
+    fn value(&self, index: usize) -> &'a T {
+        &self.data[index]
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    fn with_layout(&mut self, layout: Layout) -> MatrixViewMut<'_, T> {
+        MatrixViewMut { ptr: self.ptr, layout, marker: PhantomData }
+    }
+
+    /// Gets a mutable view of the `rows` and `cols` ranges of this view.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either range is outside the view.
+    pub fn submatrix_mut(&mut self, rows: Range<usize>, cols: Range<usize>) -> Result<MatrixViewMut<'_, T>, Error> {
+        let layout = self.layout.submatrix(rows, cols)?;
+        Ok(self.with_layout(layout))
+    }
+
+    /// Gets a mutable view of the transpose of this view.
+    pub fn transpose_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let layout = self.layout.transposed();
+        self.with_layout(layout)
+    }
+
+    /// Gets a mutable view of every `row_step`-th row and `col_step`-th
+    /// column, starting with the first.
+    ///
+    /// # Panics
+    /// Panics if either step is zero.
+    pub fn strided_mut(&mut self, row_step: usize, col_step: usize) -> MatrixViewMut<'_, T> {
+        let layout = self.layout.strided(row_step, col_step);
+        self.with_layout(layout)
+    }
+
+    /// Splits the view into disjoint mutable views of the rows before `row`
+    /// and the rows from `row` on.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is greater than the number of
+    /// rows.
+    pub fn split_at_row_mut(&mut self, row: usize) -> Result<(MatrixViewMut<'_, T>, MatrixViewMut<'_, T>), Error> {
+        let (top, bottom) = self.layout.split_at_row(row)?;
+        // SAFETY: the halves are disjoint parts of this view, which is
+        // mutably borrowed.
+        Ok(unsafe { split(self.ptr, top, bottom) })
+    }
+
+    /// Splits the view into disjoint mutable views of the columns before
+    /// `col` and the columns from `col` on.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is greater than the number of
+    /// columns.
+    pub fn split_at_col_mut(&mut self, col: usize) -> Result<(MatrixViewMut<'_, T>, MatrixViewMut<'_, T>), Error> {
+        let (left, right) = self.layout.split_at_col(col)?;
+        // SAFETY: the halves are disjoint parts of this view, which is
+        // mutably borrowed.
+        Ok(unsafe { split(self.ptr, left, right) })
+    }
+
+    /// Copies the viewed values into a new `Matrix`.
+    ///
+    /// # Errors
+    /// Returns `Error::ZeroDimension` if the view has no rows or no columns,
+    /// as a `Matrix` cannot be empty.
+    pub fn to_matrix(&self) -> Result<Matrix<T>, Error>
+    where
+        T: Clone,
+    {
+        let data = self.layout.indices().map(|index| self.value(index).clone()).collect();
+        Matrix::try_from_vec(self.layout.rows, self.layout.cols, data)
+    }
+
+    /// Gets a pointer to the value at `index` of the matrix buffer.
+    fn value_ptr(&self, index: usize) -> *mut T {
+        // SAFETY: indices come from the layout, which lies within the
+        // borrowed buffer.
+        unsafe { self.ptr.add(index) }
+    }
+
+    /// Gets the value at `index` of the matrix buffer.
+    fn value(&self, index: usize) -> &T {
+        // SAFETY: indices come from the layout, so the value belongs to this
+        // view, which is borrowed for the lifetime of the reference.
+        unsafe { &*self.value_ptr(index) }
+    }
+}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+// SAFETY: a mutable view borrows its own values like `&'a mut T`.
+unsafe impl<T: Send> Send for MatrixViewMut<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixViewMut<'_, T> {}
+
+impl<T: fmt::Debug> fmt::Debug for MatrixView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = (0..self.layout.rows)
+            .map(|row| fmt::from_fn(move |f| f.debug_list().entries(self.get_row(row).unwrap()).finish()));
+        f.debug_list().entries(rows).finish()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MatrixViewMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = (0..self.layout.rows)
+            .map(|row| fmt::from_fn(move |f| f.debug_list().entries(self.get_row(row).unwrap()).finish()));
+        f.debug_list().entries(rows).finish()
+    }
+}
+
+impl<'a, T> IsMatrix<T> for MatrixView<'a, T> {
+    /// Gets the number of rows in the view.
+    fn rows(&self) -> usize {
+        self.layout.rows
+    }
+
+    /// Gets the number of columns in the view.
+    fn cols(&self) -> usize {
+        self.layout.cols
+    }
+
+    /// Gets a reference to the value at `row`, `col` of the view, or `None`
+    /// if either is outside the view.
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if self.layout.contains(row, col) {
+            Some(self.value(self.layout.index(row, col)))
+        } else {
+            None
+        }
+    }
+
+    /// Gets an iterator of the values in `row` of the view.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is outside the view.
+    fn get_row(&self, row: usize) -> Result<RowIterator<'_, T>, Error> {
+        check_index(row, self.layout.rows)?;
+
+        let start = self.layout.index(row, 0);
+        Ok(RowIterator::strided(self.data, start, self.layout.col_stride, self.layout.cols))
+    }
+
+    /// Gets an iterator of the values in `col` of the view.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is outside the view.
+    fn get_col(&self, col: usize) -> Result<ColumnIterator<'_, T>, Error> {
+        check_index(col, self.layout.cols)?;
+
+        let start = self.layout.index(0, col);
+        Ok(ColumnIterator::strided(self.data, start, self.layout.row_stride, self.layout.rows))
+    }
+
+    /// Collects references to the values of the view into a transposed
+    /// `Matrix`. Use `transpose_view` to avoid collecting.
+    ///
+    /// # Panics
+    /// Panics if the view has no rows or no columns, as a `Matrix` cannot be
+    /// empty. Use `transpose_view` to transpose an empty view.
+    fn transpose(&self) -> Matrix<&T> {
+        let layout = self.layout.transposed();
+        let data = layout.indices().map(|index| self.value(index)).collect();
+        Matrix::try_from_vec(layout.rows, layout.cols, data)
+            .unwrap_or_else(|e| panic!("{}; use `transpose_view` instead", e))
+    }
+
+    /// Applies a function to all values of the view, row by row.
+    fn apply<F: FnMut(&T)>(&self, func: F) {
+        self.layout.indices().map(|index| self.value(index)).for_each(func);
+    }
+}
+
+impl<T> IsMatrix<T> for MatrixViewMut<'_, T> {
+    /// Gets the number of rows in the view.
+    fn rows(&self) -> usize {
+        self.layout.rows
+    }
+
+    /// Gets the number of columns in the view.
+    fn cols(&self) -> usize {
+        self.layout.cols
+    }
+
+    /// Gets a reference to the value at `row`, `col` of the view, or `None`
+    /// if either is outside the view.
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if self.layout.contains(row, col) {
+            Some(self.value(self.layout.index(row, col)))
+        } else {
+            None
+        }
+    }
+
+    /// Gets an iterator of the values in `row` of the view.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is outside the view.
+    fn get_row(&self, row: usize) -> Result<RowIterator<'_, T>, Error> {
+        check_index(row, self.layout.rows)?;
+
+        let start = self.ptr.wrapping_add(self.layout.index(row, 0));
+        // SAFETY: the layout places the values of `row` `col_stride` apart
+        // within this view, and `&self` keeps them borrowed shared for the
+        // lifetime of the iterator.
+        Ok(unsafe { RowIterator::raw(start, self.layout.col_stride, self.layout.cols) })
+    }
+
+    /// Gets an iterator of the values in `col` of the view.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is outside the view.
+    fn get_col(&self, col: usize) -> Result<ColumnIterator<'_, T>, Error> {
+        check_index(col, self.layout.cols)?;
+
+        let start = self.ptr.wrapping_add(self.layout.index(0, col));
+        // SAFETY: the layout places the values of `col` `row_stride` apart
+        // within this view, and `&self` keeps them borrowed shared for the
+        // lifetime of the iterator.
+        Ok(unsafe { ColumnIterator::raw(start, self.layout.row_stride, self.layout.rows) })
+    }
+
+    /// Collects references to the values of the view into a transposed
+    /// `Matrix`.
+    ///
+    /// # Panics
+    /// Panics if the view has no rows or no columns, as a `Matrix` cannot be
+    /// empty. Use `transpose_view_mut` to transpose an empty view.
+    fn transpose(&self) -> Matrix<&T> {
+        let layout = self.layout.transposed();
+        let data = layout.indices().map(|index| self.value(index)).collect();
+        Matrix::try_from_vec(layout.rows, layout.cols, data)
+            .unwrap_or_else(|e| panic!("{}; use `transpose_view_mut` instead", e))
+    }
+
+    /// Applies a function to all values of the view, row by row.
+    fn apply<F: FnMut(&T)>(&self, func: F) {
+        self.layout.indices().map(|index| self.value(index)).for_each(func);
+    }
+}
+
+impl<T> IsMatrixMut<T> for MatrixViewMut<'_, T> {
+    /// Gets a mutable reference to the value at `row`, `col` of the view,
+    /// or `None` if either is outside the view.
+    fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if self.layout.contains(row, col) {
+            // SAFETY: the value lies within the view, which is mutably
+            // borrowed.
+            Some(unsafe { &mut *self.value_ptr(self.layout.index(row, col)) })
+        } else {
+            None
+        }
+    }
+
+    /// Writes `value` at `row`, `col` of the view.
+    ///
+    /// # Returns
+    /// Returns a reference to the value written, or `None` if either `row`
+    /// or `col` is outside the view.
+    fn set(&mut self, row: usize, col: usize, value: T) -> Option<&T> {
+        *self.get_mut(row, col)? = value;
+        self.get(row, col)
+    }
+
+    /// Swaps rows `row_a` and `row_b` of the view.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either row is outside the view.
+    fn swap_rows(&mut self, row_a: usize, row_b: usize) -> Result<(), Error> {
+        check_index(row_a, self.layout.rows)?;
+        check_index(row_b, self.layout.rows)?;
+
+        for col in 0..self.layout.cols {
+            let a = self.value_ptr(self.layout.index(row_a, col));
+            let b = self.value_ptr(self.layout.index(row_b, col));
+            // SAFETY: both values lie within the mutably borrowed view.
+            unsafe { ptr::swap(a, b) };
+        }
+        Ok(())
+    }
+
+    /// Swaps columns `col_a` and `col_b` of the view.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either column is outside the view.
+    fn swap_cols(&mut self, col_a: usize, col_b: usize) -> Result<(), Error> {
+        self.transpose_view_mut().swap_rows(col_a, col_b)
+    }
+
+    /// Applies a function to modify all values of the view, row by row.
+    fn apply_mut<F: FnMut(&mut T)>(&mut self, mut func: F) {
+        for index in self.layout.indices() {
+            // SAFETY: each value lies within the mutably borrowed view, and
+            // is visited once.
+            func(unsafe { &mut *self.value_ptr(index) });
+        }
+    }
+}
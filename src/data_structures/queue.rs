@@ -1,9 +1,19 @@
-use std::error::Error;
+use std::collections::VecDeque;
+
+use crate::data_structures::stack::IsStack;
+use crate::error::Error;
+
+pub mod concurrent;
+
+pub use concurrent::{IsConcurrentQueue, MichaelScottQueue};
 
 /// A simple queue (FIFO) implementation with a growable size and no capacity limit.
 ///
+/// The queue is backed by a growable ring buffer, so adding to the back and
+/// removing from the front are both amortised O(1).
+///
 /// # Type parameters
-/// - `T`: Generic type that implements the `Clone` trait.
+/// - `T`: Generic type of the elements in the queue.
 ///
 /// # Examples
 ///
@@ -32,13 +42,13 @@ use std::error::Error;
 /// # }
 /// ```
 /// 
-#[derive(Debug)]
-pub struct Queue<T: Clone> {
-    queue: Vec<T>
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Queue<T> {
+    queue: VecDeque<T>
 }
 
 /// Defines methods expected on a queue data structure
-pub trait IsQueue<T: Clone> {
+pub trait IsQueue<T> {
     /// Adds a new value to a queue
     ///
     /// # Parameters
@@ -47,14 +57,14 @@ pub trait IsQueue<T: Clone> {
     /// # Returns
     /// - `Ok(_)`: If the element add was successful.
     ///     - `Some(T)`: If adding an element resulted in the removal of an
-    ///         existing one (in the case of a circular buffer, for instance)
+    ///       existing one (in the case of a circular buffer, for instance)
     ///     - `None`: Adding an element did not return any value
-    /// - `Error`: If the element add was unsuccessful
+    /// - `Err(Error::Full)`: If the element add was unsuccessful
     ///
     /// # Errors
     /// Attempting to add an element to a full queue that does not allow for
-    /// overflow will return an error.
-    fn add(&mut self, value: T) -> Result<Option<T>, Box<dyn Error>>;
+    /// overflow will return `Error::Full`.
+    fn add(&mut self, value: T) -> Result<Option<T>, Error>;
 
     /// Removes an element from the queue and returns it
     ///
@@ -63,21 +73,33 @@ pub trait IsQueue<T: Clone> {
     ///
     /// # Returns
     /// - `Ok(T)`: The oldest value in the queue
-    /// - `Error`
+    /// - `Err(Error::Empty)`: If the queue is empty
     ///
     /// # Errors
-    /// Returns an error if the queue is empty.
-    fn remove(&mut self) -> Result<T, Box<dyn Error>>;
+    /// Returns `Error::Empty` if the queue is empty.
+    fn remove(&mut self) -> Result<T, Error>;
 
-    /// Reads the head of the queue.
+    /// Gets a reference to the head of the queue.
     ///
     /// # Returns
-    /// - `Ok(T)`: The oldest value in the queue
-    /// - `Error`
+    /// - `Ok(&T)`: The oldest value in the queue
+    /// - `Err(Error::Empty)`: If the queue is empty
     ///
     /// # Errors
-    /// Returns an error if the queue is empty.
-    fn read(&self) -> Result<T, Box<dyn Error>>;
+    /// Returns `Error::Empty` if the queue is empty.
+    fn peek(&self) -> Result<&T, Error>;
+
+    /// Reads the head of the queue, returning a clone of the value `peek`
+    /// refers to.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the queue is empty.
+    fn read(&self) -> Result<T, Error>
+    where
+        T: Clone,
+    {
+        self.peek().cloned()
+    }
 
     /// Gets the size of the queue.
     ///
@@ -86,9 +108,24 @@ pub trait IsQueue<T: Clone> {
     /// values when specified, which means that the `size` of a queue with
     /// default values should always be equal to its `capacity`
     fn size(&self) -> usize;
+
+    /// Returns `true` if the queue contains no elements.
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Gets the number of elements that can be added before `add` returns
+    /// `Error::Full`.
+    ///
+    /// # Returns
+    /// - `Some(usize)`: The remaining space in a bounded queue
+    /// - `None`: If the queue never rejects an element
+    fn remaining_capacity(&self) -> Option<usize> {
+        None
+    }
 }
 
-impl<T: Clone> Queue<T> {
+impl<T> Queue<T> {
     /// Creates a new simple `Queue`.
     ///
     /// # Returns
@@ -103,11 +140,135 @@ impl<T: Clone> Queue<T> {
     /// ```
     pub fn new() -> Queue<T>
     {
-        Queue { queue: vec![] }
+        Queue { queue: VecDeque::new() }
+    }
+
+    /// Creates a new empty `Queue` with space for at least `capacity` elements.
+    ///
+    /// # Returns
+    /// Returns an empty `Queue<T>` that can hold `capacity` elements without
+    /// reallocating.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let queue: Queue<isize> = Queue::with_capacity(10);
+    ///
+    /// assert_eq!(queue.size(), 0);
+    /// assert!(queue.capacity() >= 10);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Queue<T> {
+        Queue { queue: VecDeque::with_capacity(capacity) }
+    }
+
+    /// Gets the number of elements the `Queue` can hold without reallocating.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let queue: Queue<isize> = Queue::with_capacity(10);
+    /// assert!(queue.capacity() >= 10);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
+    /// Reserves space for at least `additional` more elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let mut queue: Queue<isize> = Queue::new();
+    /// queue.add(1);
+    /// queue.reserve(10);
+    /// assert!(queue.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.queue.reserve(additional);
+    }
+
+    /// Shrinks the capacity of the `Queue` as much as possible.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let mut queue: Queue<isize> = Queue::with_capacity(100);
+    /// queue.add(1);
+    /// queue.shrink_to_fit();
+    /// assert!(queue.capacity() < 100);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.queue.shrink_to_fit();
+    }
+
+    /// Gets the contents of the `Queue` as a pair of slices.
+    ///
+    /// Because the ring buffer may wrap around, the elements are not always
+    /// stored contiguously. The first slice holds the oldest elements, and
+    /// the second slice (which may be empty) continues where the first ends.
+    ///
+    /// # Returns
+    /// Returns the queued elements, oldest first, split across two slices.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let mut queue: Queue<isize> = Queue::with_capacity(4);
+    /// for i in 0..4 {
+    ///     queue.add(i);
+    /// }
+    /// queue.remove();
+    /// queue.add(4);
+    ///
+    /// let (front, back) = queue.as_slices();
+    /// assert_eq!([front, back].concat(), [1, 2, 3, 4]);
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.queue.as_slices()
+    }
+
+    /// Iterates over the `Queue` without removing any elements.
+    ///
+    /// # Returns
+    /// Returns an iterator visiting the elements from the front of the queue
+    /// (the oldest element) to the back, in the order `remove` would return
+    /// them.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// # fn main() {
+    /// let queue = queue![1, 2, 3];
+    /// let values: Vec<&isize> = queue.iter().collect();
+    ///
+    /// assert_eq!(values, [&1, &2, &3]);
+    /// assert_eq!(queue.size(), 3);
+    /// # }
+    /// ```
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, T> {
+        self.queue.iter()
+    }
+
+    /// Removes every element from the `Queue`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// # fn main() {
+    /// let mut queue = queue![1, 2, 3];
+    /// queue.clear();
+    ///
+    /// assert!(queue.is_empty());
+    /// # }
+    /// ```
+    pub fn clear(&mut self) {
+        self.queue.clear();
     }
 }
 
-impl<T: Clone> Default for Queue<T> {
+impl<T> Default for Queue<T> {
     /// Initializes a `Default` `Queue`.
     ///
     /// # Returns
@@ -120,11 +281,64 @@ impl<T: Clone> Default for Queue<T> {
     /// assert_eq!(queue.size(), 0);
     /// ```
     fn default() -> Queue<T> {
-        Queue { queue: vec![] }
+        Queue { queue: VecDeque::new() }
+    }
+}
+
+impl<T> FromIterator<T> for Queue<T> {
+    /// Builds a `Queue` by adding each element in turn, so the first element
+    /// yielded by the iterator is at the front.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let queue: Queue<isize> = (1..=3).collect();
+    /// assert_eq!(queue.peek().unwrap(), &1);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Queue<T> {
+        Queue { queue: iter.into_iter().collect() }
+    }
+}
+
+impl<T> Extend<T> for Queue<T> {
+    /// Adds each element in turn to the back of the `Queue`.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.queue.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = std::collections::vec_deque::IntoIter<T>;
+
+    /// Drains the `Queue` in FIFO order, oldest element first.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// # fn main() {
+    /// let queue = queue![1, 2, 3];
+    /// let values: Vec<isize> = queue.into_iter().collect();
+    ///
+    /// assert_eq!(values, [1, 2, 3]);
+    /// # }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.queue.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
-impl<T: Clone> IsQueue<T> for Queue<T> {
+impl<T> IsQueue<T> for Queue<T> {
     /// Adds a new element to the end of the `Queue`.
     ///
     /// # Parameters
@@ -140,8 +354,8 @@ impl<T: Clone> IsQueue<T> for Queue<T> {
     /// assert_eq!(queue.add(42).unwrap(), None);
     /// assert_eq!(queue.size(), 1);
     /// ```
-    fn add(&mut self, value: T) -> Result<Option<T>, Box<dyn Error>> {
-        self.queue.push(value);
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        self.queue.push_back(value);
         Ok(None)
     }
 
@@ -149,35 +363,34 @@ impl<T: Clone> IsQueue<T> for Queue<T> {
     ///
     /// # Returns
     /// - `Ok(T)`: The oldest value in the queue
-    /// - `Error`
+    /// - `Err(Error::Empty)`: If the queue is empty
     ///
     /// # Errors
-    /// Returns an error if the `Queue` is empty.
+    /// Returns `Error::Empty` if the `Queue` is empty.
     ///
     /// # Examples
     /// ```
     /// # use crate::algorithms::data_structures::queue::*;
+    /// # use crate::algorithms::error::Error;
     /// let mut q: Queue<isize> = Queue::new();
     /// q.add(42);
     /// assert_eq!(q.remove().unwrap(), 42);
     /// assert_eq!(q.size(), 0);
+    ///
+    /// assert_eq!(q.remove(), Err(Error::Empty));
     /// ```
-    fn remove(&mut self) -> Result<T, Box<dyn Error>> {
-        if !self.queue.is_empty() {
-            Ok(self.queue.remove(0))
-        } else {
-            panic!("The queue is empty")
-        }
+    fn remove(&mut self) -> Result<T, Error> {
+        self.queue.pop_front().ok_or(Error::Empty)
     }
 
-    /// Reads the oldest value in the `Queue`.
+    /// Gets a reference to the oldest value in the `Queue`.
     ///
     /// # Returns
-    /// - `T`: The oldest value in the queue
-    /// - `Error`
+    /// - `Ok(&T)`: The oldest value in the queue
+    /// - `Err(Error::Empty)`: If the queue is empty
     ///
     /// # Errors
-    /// Returns an error if the `Queue` is empty.
+    /// Returns `Error::Empty` if the `Queue` is empty.
     ///
     /// # Examples
     ///
@@ -185,13 +398,10 @@ impl<T: Clone> IsQueue<T> for Queue<T> {
     /// # use crate::algorithms::data_structures::queue::*;
     /// let mut queue: Queue<isize> = Queue::new();
     /// queue.add(42);
-    /// assert_eq!(queue.read().unwrap(), 42);
+    /// assert_eq!(queue.peek().unwrap(), &42);
     /// ```
-    fn read(&self) -> Result<T, Box<dyn Error>> {
-        match self.queue.first() {
-            Some(val) => Ok(val.clone()),
-            None => panic!("The queue is empty"),
-        }
+    fn peek(&self) -> Result<&T, Error> {
+        self.queue.front().ok_or(Error::Empty)
     }
 
     /// Gets the size of the `Queue`.
@@ -213,6 +423,488 @@ impl<T: Clone> IsQueue<T> for Queue<T> {
     }
 }
 
+/// A queue (FIFO) with a fixed capacity that rejects elements once full.
+///
+/// Unlike `Queue`, adding to a full `BoundedQueue` does not grow the queue;
+/// it returns `Error::Full` and leaves the queue unchanged. This makes it
+/// suitable for applying back-pressure to producers.
+///
+/// # Type parameters
+/// - `T`: Generic type of the elements in the queue.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::queue::*;
+/// # use crate::algorithms::error::Error;
+/// let mut queue = BoundedQueue::new(2);
+///
+/// assert_eq!(queue.add(1).unwrap(), None);
+/// assert_eq!(queue.add(2).unwrap(), None);
+/// assert_eq!(queue.add(3), Err(Error::Full));
+///
+/// assert_eq!(queue.remove().unwrap(), 1);
+/// assert_eq!(queue.remaining(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundedQueue<T> {
+    queue: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> BoundedQueue<T> {
+    /// Creates a new empty `BoundedQueue` holding at most `capacity` elements.
+    ///
+    /// # Returns
+    /// Returns an empty `BoundedQueue<T>`.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let queue: BoundedQueue<isize> = BoundedQueue::new(5);
+    ///
+    /// assert_eq!(queue.size(), 0);
+    /// assert_eq!(queue.capacity(), 5);
+    /// ```
+    pub fn new(capacity: usize) -> BoundedQueue<T> {
+        assert!(capacity > 0);
+
+        BoundedQueue { queue: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    /// Gets the maximum number of elements the `BoundedQueue` can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns `true` if the `BoundedQueue` is at capacity, meaning the next
+    /// `add` will fail.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let mut queue = BoundedQueue::new(1);
+    /// assert!(!queue.is_full());
+    ///
+    /// queue.add(42);
+    /// assert!(queue.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.queue.len() >= self.capacity
+    }
+
+    /// Gets the number of elements that can be added before the
+    /// `BoundedQueue` is full.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let mut queue = BoundedQueue::new(3);
+    /// queue.add(42);
+    /// assert_eq!(queue.remaining(), 2);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.queue.len()
+    }
+}
+
+impl<T> IsQueue<T> for BoundedQueue<T> {
+    /// Adds a new element to the end of the `BoundedQueue`.
+    ///
+    /// # Parameters
+    /// - `value`: Value to add to the queue
+    ///
+    /// # Returns
+    /// - `Ok(None)`: If the element was added
+    /// - `Err(Error::Full)`: If the queue is at capacity
+    ///
+    /// # Errors
+    /// Returns `Error::Full` if the `BoundedQueue` is at capacity. The
+    /// element is not added.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// # use crate::algorithms::error::Error;
+    /// let mut queue = BoundedQueue::new(1);
+    /// assert_eq!(queue.add(42).unwrap(), None);
+    /// assert_eq!(queue.add(43), Err(Error::Full));
+    /// ```
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        if self.is_full() {
+            return Err(Error::Full);
+        }
+
+        self.queue.push_back(value);
+        Ok(None)
+    }
+
+    /// Removes the oldest value from the `BoundedQueue` and returns it.
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The oldest value in the queue
+    /// - `Err(Error::Empty)`: If the queue is empty
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `BoundedQueue` is empty.
+    fn remove(&mut self) -> Result<T, Error> {
+        self.queue.pop_front().ok_or(Error::Empty)
+    }
+
+    /// Gets a reference to the oldest value in the `BoundedQueue`.
+    ///
+    /// # Returns
+    /// - `Ok(&T)`: The oldest value in the queue
+    /// - `Err(Error::Empty)`: If the queue is empty
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `BoundedQueue` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let mut queue = BoundedQueue::new(2);
+    /// queue.add(42);
+    /// queue.add(43);
+    /// assert_eq!(queue.peek().unwrap(), &42);
+    /// ```
+    fn peek(&self) -> Result<&T, Error> {
+        self.queue.front().ok_or(Error::Empty)
+    }
+
+    /// Gets the size of the `BoundedQueue`.
+    ///
+    /// # Returns
+    /// Returns the number of elements in the queue.
+    fn size(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Gets the number of elements that can be added before the
+    /// `BoundedQueue` is full.
+    fn remaining_capacity(&self) -> Option<usize> {
+        Some(self.remaining())
+    }
+}
+
+/// A queue (FIFO) that also answers `min` and `max` queries over its
+/// current contents, as used for sliding-window extrema.
+///
+/// Besides the queued elements, the queue keeps two monotonic deques of
+/// positions: one whose elements increase from front to back (candidates for
+/// the minimum) and one whose elements decrease (candidates for the
+/// maximum). Adding an element discards any candidates it dominates, so
+/// `add` is amortised O(1), while `remove`, `min` and `max` are O(1).
+///
+/// # Type parameters
+/// - `T`: Generic type that implements the `PartialOrd` trait.
+///
+/// # Examples
+///
+/// A sliding window of width 3:
+///
+/// ```
+/// # use crate::algorithms::data_structures::queue::*;
+/// let mut window = MonotonicQueue::new();
+/// let mut maxima = vec![];
+///
+/// for value in [1, 3, -1, -3, 5, 3, 6, 7] {
+///     window.add(value).unwrap();
+///     if window.size() > 3 {
+///         window.remove().unwrap();
+///     }
+///     if window.size() == 3 {
+///         maxima.push(*window.max().unwrap());
+///     }
+/// }
+///
+/// assert_eq!(maxima, [3, 3, 5, 5, 6, 7]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonotonicQueue<T: PartialOrd> {
+    queue: VecDeque<T>,
+    mins: VecDeque<usize>,
+    maxs: VecDeque<usize>,
+    head: usize,
+}
+
+impl<T: PartialOrd> MonotonicQueue<T> {
+    /// Creates a new empty `MonotonicQueue`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let queue: MonotonicQueue<isize> = MonotonicQueue::new();
+    /// assert_eq!(queue.size(), 0);
+    /// ```
+    pub fn new() -> MonotonicQueue<T> {
+        MonotonicQueue {
+            queue: VecDeque::new(),
+            mins: VecDeque::new(),
+            maxs: VecDeque::new(),
+            head: 0,
+        }
+    }
+
+    /// Gets a reference to the smallest element in the queue.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// # use crate::algorithms::error::Error;
+    /// let mut queue = MonotonicQueue::new();
+    /// assert_eq!(queue.min(), Err(Error::Empty));
+    ///
+    /// queue.add(1).unwrap();
+    /// queue.add(3).unwrap();
+    /// assert_eq!(queue.min().unwrap(), &1);
+    ///
+    /// queue.remove().unwrap();
+    /// assert_eq!(queue.min().unwrap(), &3);
+    /// ```
+    pub fn min(&self) -> Result<&T, Error> {
+        let position = self.mins.front().ok_or(Error::Empty)?;
+        Ok(self.at(*position))
+    }
+
+    /// Gets a reference to the largest element in the queue.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the queue is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// let mut queue = MonotonicQueue::new();
+    /// queue.add(3).unwrap();
+    /// queue.add(1).unwrap();
+    /// assert_eq!(queue.max().unwrap(), &3);
+    ///
+    /// queue.remove().unwrap();
+    /// assert_eq!(queue.max().unwrap(), &1);
+    /// ```
+    pub fn max(&self) -> Result<&T, Error> {
+        let position = self.maxs.front().ok_or(Error::Empty)?;
+        Ok(self.at(*position))
+    }
+
+    /// Gets the element at an absolute `position`, counted from the first
+    /// element ever added.
+    fn at(&self, position: usize) -> &T {
+        &self.queue[position - self.head]
+    }
+}
+
+impl<T: PartialOrd> Default for MonotonicQueue<T> {
+    /// Initializes a `Default` `MonotonicQueue`.
+    fn default() -> MonotonicQueue<T> {
+        MonotonicQueue::new()
+    }
+}
+
+impl<T: PartialOrd> IsQueue<T> for MonotonicQueue<T> {
+    /// Adds a new element to the end of the `MonotonicQueue`.
+    ///
+    /// # Returns
+    /// `None` as the element addition should always be successful.
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        // Older elements that are no better than `value` can never become
+        // the extremum again, as `value` will outlive them in the queue.
+        while let Some(&back) = self.mins.back() {
+            if self.at(back) <= &value {
+                break;
+            }
+            self.mins.pop_back();
+        }
+        while let Some(&back) = self.maxs.back() {
+            if self.at(back) >= &value {
+                break;
+            }
+            self.maxs.pop_back();
+        }
+
+        let position = self.head + self.queue.len();
+        self.mins.push_back(position);
+        self.maxs.push_back(position);
+        self.queue.push_back(value);
+        Ok(None)
+    }
+
+    /// Removes the oldest value from the `MonotonicQueue` and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `MonotonicQueue` is empty.
+    fn remove(&mut self) -> Result<T, Error> {
+        let value = self.queue.pop_front().ok_or(Error::Empty)?;
+        if self.mins.front() == Some(&self.head) {
+            self.mins.pop_front();
+        }
+        if self.maxs.front() == Some(&self.head) {
+            self.maxs.pop_front();
+        }
+        self.head += 1;
+        Ok(value)
+    }
+
+    /// Gets a reference to the oldest value in the `MonotonicQueue`.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `MonotonicQueue` is empty.
+    fn peek(&self) -> Result<&T, Error> {
+        self.queue.front().ok_or(Error::Empty)
+    }
+
+    /// Gets the size of the `MonotonicQueue`.
+    fn size(&self) -> usize {
+        self.queue.len()
+    }
+}
+
+/// A queue (FIFO) built from two stacks.
+///
+/// New elements are added to an inbox stack, and elements are removed from
+/// an outbox stack. Whenever the outbox runs dry, the whole inbox is moved
+/// across, which reverses it into FIFO order. Each element is therefore added
+/// and removed at most twice, so every operation is amortised O(1) even
+/// though a single `remove` may move the whole inbox.
+///
+/// The queue works with any `IsStack` implementation. A bounded outbox must
+/// take the whole inbox in one go, so `add` keeps the inbox no larger than the
+/// outbox's capacity (as reported by `remaining_capacity`) and returns
+/// `Error::Full` beyond that.
+///
+/// # Type parameters
+/// - `S`: Backing stack type that implements the `IsStack` trait.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::queue::*;
+/// # use crate::algorithms::data_structures::stack::Stack;
+/// let mut queue: TwoStackQueue<Stack<isize>> = TwoStackQueue::new();
+/// queue.add(1).unwrap();
+/// queue.add(2).unwrap();
+/// queue.add(3).unwrap();
+///
+/// assert_eq!(queue.remove().unwrap(), 1);
+/// assert_eq!(queue.peek().unwrap(), &2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TwoStackQueue<S> {
+    inbox: S,
+    outbox: S,
+}
+
+impl<S: Default> TwoStackQueue<S> {
+    /// Creates a new empty `TwoStackQueue` with default backing stacks.
+    pub fn new() -> TwoStackQueue<S> {
+        TwoStackQueue { inbox: S::default(), outbox: S::default() }
+    }
+}
+
+impl<S> TwoStackQueue<S> {
+    /// Creates a new `TwoStackQueue` on top of the given stacks, which are
+    /// expected to be empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::*;
+    /// # use crate::algorithms::data_structures::stack::BoundedStack;
+    /// let mut queue = TwoStackQueue::from_stacks(BoundedStack::new(2), BoundedStack::new(2));
+    /// for i in 0..3 {
+    ///     queue.add(i).unwrap();
+    /// }
+    ///
+    /// // The first element waits alone in the outbox, so the inbox fills up
+    /// assert_eq!(queue.remaining_capacity(), Some(0));
+    /// assert!(queue.add(3).is_err());
+    /// assert_eq!(queue.remove().unwrap(), 0);
+    /// ```
+    pub fn from_stacks(inbox: S, outbox: S) -> TwoStackQueue<S> {
+        TwoStackQueue { inbox, outbox }
+    }
+
+    /// Consumes the queue, returning the inbox and outbox stacks.
+    pub fn into_stacks(self) -> (S, S) {
+        (self.inbox, self.outbox)
+    }
+}
+
+impl<S: Default> Default for TwoStackQueue<S> {
+    /// Initializes a `Default` `TwoStackQueue`.
+    fn default() -> TwoStackQueue<S> {
+        TwoStackQueue::new()
+    }
+}
+
+impl<T, S: IsStack<T>> IsQueue<T> for TwoStackQueue<S> {
+    /// Adds a new element to the end of the `TwoStackQueue`.
+    ///
+    /// The outbox is kept non-empty whenever the queue is not empty, so
+    /// the first element added to an empty queue goes straight to the outbox.
+    ///
+    /// # Errors
+    /// Returns `Error::Full` if the backing stack is full, or if the inbox
+    /// already holds as many elements as the outbox can take.
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        if self.outbox.is_empty() {
+            return self.outbox.add(value);
+        }
+        if capacity(&self.outbox).is_some_and(|capacity| self.inbox.size() >= capacity) {
+            return Err(Error::Full);
+        }
+        self.inbox.add(value)
+    }
+
+    /// Removes the oldest value from the `TwoStackQueue` and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `TwoStackQueue` is empty.
+    fn remove(&mut self) -> Result<T, Error> {
+        let value = self.outbox.remove()?;
+        if self.outbox.is_empty() {
+            // Reversing the inbox puts its oldest element on top. `add` keeps
+            // the inbox within the outbox's capacity, so none of it is lost.
+            while let Ok(value) = self.inbox.remove() {
+                let moved = self.outbox.add(value);
+                debug_assert!(moved.is_ok(), "the outbox cannot hold the inbox");
+            }
+        }
+        Ok(value)
+    }
+
+    /// Gets a reference to the oldest value in the `TwoStackQueue`.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `TwoStackQueue` is empty.
+    fn peek(&self) -> Result<&T, Error> {
+        self.outbox.peek()
+    }
+
+    /// Gets the size of the `TwoStackQueue`.
+    fn size(&self) -> usize {
+        self.inbox.size() + self.outbox.size()
+    }
+
+    /// Gets the number of elements that can be added before `add` returns
+    /// `Error::Full`, which is limited by the smaller backing stack.
+    fn remaining_capacity(&self) -> Option<usize> {
+        let room = [capacity(&self.inbox), capacity(&self.outbox)].into_iter().flatten().min()?;
+        Some(room.saturating_sub(self.inbox.size()) + usize::from(self.outbox.is_empty()))
+    }
+}
+
+/// Gets the total number of elements a bounded `stack` can hold.
+fn capacity<T, S: IsStack<T>>(stack: &S) -> Option<usize> {
+    stack.remaining_capacity().map(|free| stack.size() + free)
+}
+
 /// Creates a new `Queue<T>`
 ///
 /// Delegates to the default queue initializer. Note that the values are
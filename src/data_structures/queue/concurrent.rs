@@ -0,0 +1,210 @@
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crossbeam_epoch::{self as epoch, Atomic, Owned, Shared};
+
+use crate::error::Error;
+
+/// Defines methods expected on a queue shared between threads.
+///
+/// The methods mirror `IsQueue`, but take `&self` so a queue can be wrapped
+/// in an `Arc` and shared by any number of producer and consumer threads.
+/// There is no `peek`: once a reference to the head was handed out, another
+/// thread could remove and drop that element while the reference is still in
+/// use.
+pub trait IsConcurrentQueue<T> {
+    /// Adds a new value to the back of the queue.
+    ///
+    /// # Returns
+    /// - `Ok(None)`: If the element was added
+    /// - `Err(Error::Full)`: If the queue is bounded and at capacity
+    fn add(&self, value: T) -> Result<Option<T>, Error>;
+
+    /// Removes the oldest value from the queue and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the queue was empty when checked.
+    fn remove(&self) -> Result<T, Error>;
+
+    /// Gets the number of elements in the queue.
+    ///
+    /// Other threads may add or remove at any time, so the result is only a
+    /// snapshot.
+    fn size(&self) -> usize;
+
+    /// Returns `true` if the queue contained no elements when checked.
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+}
+
+struct Node<T> {
+    value: MaybeUninit<T>,
+    next: Atomic<Node<T>>,
+}
+
+/// A lock-free multi-producer, multi-consumer queue (FIFO), as described by
+/// M. M. Michael and M. L. Scott.
+///
+/// The queue is a singly linked list that always starts with a sentinel
+/// node. Producers link new nodes after the tail and consumers advance the
+/// head, each with a compare-and-swap; a thread that finds the tail lagging
+/// behind helps move it forward rather than waiting. Removed nodes are
+/// retired through epoch-based reclamation, so a node is only freed once no
+/// thread can still be reading it.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use std::thread;
+/// # use crate::algorithms::data_structures::queue::{IsConcurrentQueue, MichaelScottQueue};
+/// let queue = Arc::new(MichaelScottQueue::new());
+///
+/// let producer = {
+///     let queue = Arc::clone(&queue);
+///     thread::spawn(move || {
+///         for i in 0..100 {
+///             queue.add(i).unwrap();
+///         }
+///     })
+/// };
+/// producer.join().unwrap();
+///
+/// // Values from a single producer come out in the order they went in
+/// assert_eq!(queue.remove().unwrap(), 0);
+/// assert_eq!(queue.remove().unwrap(), 1);
+/// ```
+pub struct MichaelScottQueue<T> {
+    head: Atomic<Node<T>>,
+    tail: Atomic<Node<T>>,
+    len: AtomicUsize,
+}
+
+impl<T> MichaelScottQueue<T> {
+    /// Creates a new empty `MichaelScottQueue`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::queue::{IsConcurrentQueue, MichaelScottQueue};
+    /// let queue: MichaelScottQueue<isize> = MichaelScottQueue::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> MichaelScottQueue<T> {
+        let queue = MichaelScottQueue {
+            head: Atomic::null(),
+            tail: Atomic::null(),
+            len: AtomicUsize::new(0),
+        };
+
+        // SAFETY: the queue has not been shared yet, so no other thread can
+        // observe the sentinel.
+        let sentinel = Owned::new(Node { value: MaybeUninit::uninit(), next: Atomic::null() })
+            .into_shared(unsafe { epoch::unprotected() });
+        queue.head.store(sentinel, Ordering::Relaxed);
+        queue.tail.store(sentinel, Ordering::Relaxed);
+        queue
+    }
+}
+
+impl<T> Default for MichaelScottQueue<T> {
+    /// Initializes a `Default` `MichaelScottQueue`.
+    fn default() -> MichaelScottQueue<T> {
+        MichaelScottQueue::new()
+    }
+}
+
+impl<T> IsConcurrentQueue<T> for MichaelScottQueue<T> {
+    /// Adds a new value to the back of the `MichaelScottQueue`.
+    ///
+    /// # Returns
+    /// `Ok(None)` as the element addition should always be successful.
+    fn add(&self, value: T) -> Result<Option<T>, Error> {
+        // Count the element before publishing it, so a concurrent `remove`
+        // can never bring the count below zero.
+        self.len.fetch_add(1, Ordering::Relaxed);
+
+        let guard = epoch::pin();
+        let node = Owned::new(Node { value: MaybeUninit::new(value), next: Atomic::null() })
+            .into_shared(&guard);
+
+        loop {
+            let tail = self.tail.load(Ordering::Acquire, &guard);
+            // SAFETY: the tail is never null, and the guard keeps it alive.
+            let last = unsafe { tail.deref() };
+            let next = last.next.load(Ordering::Acquire, &guard);
+
+            if !next.is_null() {
+                // Another producer linked a node but has not moved the tail
+                // yet; help it along and retry.
+                let _ = self.tail.compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed, &guard);
+                continue;
+            }
+
+            if last.next
+                .compare_exchange(Shared::null(), node, Ordering::Release, Ordering::Relaxed, &guard)
+                .is_ok()
+            {
+                let _ = self.tail.compare_exchange(tail, node, Ordering::Release, Ordering::Relaxed, &guard);
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Removes the oldest value from the `MichaelScottQueue` and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `MichaelScottQueue` was empty when
+    /// checked.
+    fn remove(&self) -> Result<T, Error> {
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Ordering::Acquire, &guard);
+            // SAFETY: the head is never null, and the guard keeps it alive.
+            let sentinel = unsafe { head.deref() };
+            let next = sentinel.next.load(Ordering::Acquire, &guard);
+            // SAFETY: as above, for the node after the sentinel.
+            let first = unsafe { next.as_ref() }.ok_or(Error::Empty)?;
+
+            if self.head
+                .compare_exchange(head, next, Ordering::Release, Ordering::Relaxed, &guard)
+                .is_ok()
+            {
+                // Never let the tail point at a retired node.
+                let tail = self.tail.load(Ordering::Relaxed, &guard);
+                if tail == head {
+                    let _ = self.tail.compare_exchange(tail, next, Ordering::Release, Ordering::Relaxed, &guard);
+                }
+
+                self.len.fetch_sub(1, Ordering::Relaxed);
+                // SAFETY: the successful swap made `first` the new sentinel,
+                // whose value is never read again, so this thread is the only
+                // one to move it out. The old sentinel is freed only after
+                // every pinned thread has moved on.
+                unsafe {
+                    guard.defer_destroy(head);
+                    return Ok(first.value.assume_init_read());
+                }
+            }
+        }
+    }
+
+    /// Gets the number of elements in the `MichaelScottQueue`.
+    fn size(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Drop for MichaelScottQueue<T> {
+    fn drop(&mut self) {
+        while self.remove().is_ok() {}
+
+        // SAFETY: `&mut self` guarantees no other thread is using the queue,
+        // so the remaining sentinel, whose value was already moved out or
+        // never set, can be freed immediately.
+        unsafe {
+            let sentinel = self.head.load(Ordering::Relaxed, epoch::unprotected());
+            drop(sentinel.into_owned());
+        }
+    }
+}
@@ -0,0 +1,586 @@
+use std::iter::Zip;
+use std::slice;
+
+use num_traits::Num;
+
+use crate::data_structures::matrix::{check_index, IsMatrix, Matrix};
+use crate::error::Error;
+
+mod multiply;
+mod solvers;
+
+pub use solvers::{
+    IdentityPreconditioner, IsLinearOperator, IsPreconditioner, IterativeSolution, JacobiPreconditioner, SolverOptions,
+};
+
+/// A sparse matrix, storing only its nonzero values.
+///
+/// Positions that are not stored hold zero.
+pub trait IsSparseMatrix<T> {
+    fn rows(&self) -> usize;
+    fn cols(&self) -> usize;
+    fn nnz(&self) -> usize;
+    fn get(&self, row: usize, col: usize) -> Option<&T>;
+    fn get_row(&self, row: usize) -> Result<SparseRowIterator<'_, T>, Error>;
+    fn get_col(&self, col: usize) -> Result<SparseColumnIterator<'_, T>, Error>;
+}
+
+/// A sparse matrix in coordinate (COO) format, used to build a `CsrMatrix`
+/// or `CscMatrix`.
+///
+/// Entries are stored as (`row`, `col`, `value`) triplets in the order they
+/// are pushed, so building is cheap but lookups are not supported. Pushing
+/// the same position more than once adds the values together on
+/// conversion.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::sparse::*;
+/// let mut coo = CooMatrix::new(3, 3);
+/// coo.push(0, 0, 2.0).unwrap();
+/// coo.push(2, 1, 5.0).unwrap();
+/// coo.push(2, 1, 1.0).unwrap();
+/// assert!(coo.push(3, 0, 1.0).is_err());
+///
+/// let csr = coo.to_csr();
+/// assert_eq!(csr.nnz(), 2);
+/// assert_eq!(csr.get(2, 1), Some(&6.0));
+/// assert_eq!(csr.get(1, 1), None);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CooMatrix<T> {
+    rows: usize,
+    cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+/// A sparse matrix in compressed sparse row (CSR) format.
+///
+/// The nonzero values are stored row by row, sorted by column, so iterating
+/// over a row and multiplying by a vector are fast.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # use crate::algorithms::data_structures::sparse::*;
+/// # fn main() {
+/// let dense = matrix![[1, 0, 0], [0, 0, 2], [3, 0, 4]];
+/// let csr = CsrMatrix::from_dense(&dense);
+///
+/// assert_eq!(csr.nnz(), 4);
+/// assert_eq!(csr.get_row(2).unwrap().collect::<Vec<_>>(), [(0, &3), (2, &4)]);
+/// assert_eq!(csr.to_dense(), dense);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsrMatrix<T> {
+    storage: Compressed<T>,
+}
+
+/// A sparse matrix in compressed sparse column (CSC) format.
+///
+/// The nonzero values are stored column by column, sorted by row, so
+/// iterating over a column is fast.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use] extern crate algorithms;
+/// # use crate::algorithms::data_structures::matrix::*;
+/// # use crate::algorithms::data_structures::sparse::*;
+/// # fn main() {
+/// let dense = matrix![[1, 0, 0], [0, 0, 2], [3, 0, 4]];
+/// let csc = CscMatrix::from_dense(&dense);
+///
+/// assert_eq!(csc.get_col(0).unwrap().collect::<Vec<_>>(), [(0, &1), (2, &3)]);
+/// assert_eq!(csc.get_col(1).unwrap().count(), 0);
+/// assert_eq!(csc.to_csr(), CsrMatrix::from_dense(&dense));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CscMatrix<T> {
+    storage: Compressed<T>,
+}
+
+/// Compressed storage shared by the CSR and CSC formats.
+///
+/// The matrix is split into `major` lanes (rows for CSR, columns for CSC)
+/// of length `minor`. Lane `i` holds the entries at
+/// `offsets[i]..offsets[i + 1]` of `indices` and `values`, sorted by minor
+/// index, with no duplicates or stored zeros.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Compressed<T> {
+    major: usize,
+    minor: usize,
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CooMatrix<T> {
+    /// Constructs an empty *m x n* `CooMatrix`, with *m* `rows` and *n*
+    /// `cols`.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are zero.
+    pub fn new(rows: usize, cols: usize) -> CooMatrix<T> {
+        CooMatrix::with_capacity(rows, cols, 0)
+    }
+
+    /// Constructs an empty `CooMatrix` with room for `capacity` entries.
+    ///
+    /// # Panics
+    /// Panics if either `rows` or `cols` are zero.
+    pub fn with_capacity(rows: usize, cols: usize, capacity: usize) -> CooMatrix<T> {
+        assert!(rows > 0 && cols > 0);
+
+        CooMatrix { rows, cols, entries: Vec::with_capacity(capacity) }
+    }
+
+    /// Adds `value` at `row`, `col`.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if either `row` or `col` is outside the
+    /// matrix.
+    pub fn push(&mut self, row: usize, col: usize, value: T) -> Result<(), Error> {
+        check_index(row, self.rows)?;
+        check_index(col, self.cols)?;
+
+        self.entries.push((row, col, value));
+        Ok(())
+    }
+
+    /// Gets the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Gets the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Gets the number of entries pushed, counting duplicates.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if no entries have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Gets an iterator over the (`row`, `col`, `value`) entries, in the
+    /// order they were pushed.
+    pub fn iter(&self) -> slice::Iter<'_, (usize, usize, T)> {
+        self.entries.iter()
+    }
+}
+
+impl<T: Num + Copy> CooMatrix<T> {
+    /// Converts to compressed sparse row format, summing duplicate entries
+    /// and dropping zeros.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let entries = self.entries.iter().map(|&(row, col, value)| (row, col, value));
+        CsrMatrix { storage: Compressed::from_entries(self.rows, self.cols, entries) }
+    }
+
+    /// Converts to compressed sparse column format, summing duplicate
+    /// entries and dropping zeros.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let entries = self.entries.iter().map(|&(row, col, value)| (col, row, value));
+        CscMatrix { storage: Compressed::from_entries(self.cols, self.rows, entries) }
+    }
+
+    /// Converts to a dense `Matrix`, summing duplicate entries.
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut data = vec![T::zero(); self.rows * self.cols];
+        for &(row, col, value) in &self.entries {
+            data[row * self.cols + col] = data[row * self.cols + col] + value;
+        }
+        Matrix::from_vec(self.rows, self.cols, data)
+    }
+}
+
+impl<T: Copy> From<&CsrMatrix<T>> for CooMatrix<T> {
+    fn from(csr: &CsrMatrix<T>) -> CooMatrix<T> {
+        let entries = csr.storage.entries().map(|(row, col, &value)| (row, col, value)).collect();
+        CooMatrix { rows: csr.rows(), cols: csr.cols(), entries }
+    }
+}
+
+impl<T: Copy> From<&CscMatrix<T>> for CooMatrix<T> {
+    fn from(csc: &CscMatrix<T>) -> CooMatrix<T> {
+        let entries = csc.storage.entries().map(|(col, row, &value)| (row, col, value)).collect();
+        CooMatrix { rows: csc.rows(), cols: csc.cols(), entries }
+    }
+}
+
+impl<T: Num + Copy> CsrMatrix<T> {
+    /// Constructs a `CsrMatrix` from the nonzero values of a dense `Matrix`.
+    pub fn from_dense(matrix: &Matrix<T>) -> CsrMatrix<T> {
+        let rows = (0..matrix.rows()).map(|row| matrix.get_row(row).unwrap());
+        CsrMatrix { storage: Compressed::from_lanes(matrix.rows(), matrix.cols(), rows) }
+    }
+
+    /// Converts to a dense `Matrix`.
+    pub fn to_dense(&self) -> Matrix<T> {
+        self.storage.to_dense(false)
+    }
+
+    /// Converts to compressed sparse column format.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix { storage: self.storage.transposed() }
+    }
+
+    /// Gets the transpose of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::data_structures::sparse::*;
+    /// # fn main() {
+    /// let csr = CsrMatrix::from_dense(&matrix![[1, 0, 2], [0, 3, 0]]);
+    /// assert_eq!(csr.transpose().to_dense(), matrix![[1, 0], [0, 3], [2, 0]]);
+    /// # }
+    /// ```
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        CsrMatrix { storage: self.storage.transposed() }
+    }
+}
+
+impl<T> CsrMatrix<T> {
+    /// Gets the row offsets: row `i` is stored at
+    /// `offsets[i]..offsets[i + 1]` of `col_indices` and `values`.
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+
+    /// Gets the column of each stored value.
+    pub fn col_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    /// Gets the stored values, row by row.
+    pub fn values(&self) -> &[T] {
+        &self.storage.values
+    }
+}
+
+impl<T: Num + Copy> CscMatrix<T> {
+    /// Constructs a `CscMatrix` from the nonzero values of a dense `Matrix`.
+    pub fn from_dense(matrix: &Matrix<T>) -> CscMatrix<T> {
+        let cols = (0..matrix.cols()).map(|col| matrix.get_col(col).unwrap());
+        CscMatrix { storage: Compressed::from_lanes(matrix.cols(), matrix.rows(), cols) }
+    }
+
+    /// Converts to a dense `Matrix`.
+    pub fn to_dense(&self) -> Matrix<T> {
+        self.storage.to_dense(true)
+    }
+
+    /// Converts to compressed sparse row format.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix { storage: self.storage.transposed() }
+    }
+
+    /// Gets the transpose of the matrix.
+    pub fn transpose(&self) -> CscMatrix<T> {
+        CscMatrix { storage: self.storage.transposed() }
+    }
+}
+
+impl<T> CscMatrix<T> {
+    /// Gets the column offsets: column `j` is stored at
+    /// `offsets[j]..offsets[j + 1]` of `row_indices` and `values`.
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+
+    /// Gets the row of each stored value.
+    pub fn row_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    /// Gets the stored values, column by column.
+    pub fn values(&self) -> &[T] {
+        &self.storage.values
+    }
+}
+
+impl<T> IsSparseMatrix<T> for CsrMatrix<T> {
+    /// Gets the number of rows.
+    fn rows(&self) -> usize {
+        self.storage.major
+    }
+
+    /// Gets the number of columns.
+    fn cols(&self) -> usize {
+        self.storage.minor
+    }
+
+    /// Gets the number of stored values.
+    fn nnz(&self) -> usize {
+        self.storage.values.len()
+    }
+
+    /// Gets a reference to the value stored at `row`, `col`.
+    ///
+    /// # Returns
+    /// Returns `None` if the value is zero, or if either `row` or `col` is
+    /// outside the matrix.
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.storage.get(row, col)
+    }
+
+    /// Gets an iterator of the (`col`, `value`) pairs stored in `row`, in
+    /// column order.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is outside the matrix.
+    fn get_row(&self, row: usize) -> Result<SparseRowIterator<'_, T>, Error> {
+        Ok(SparseRowIterator { lane: self.storage.lane(row)? })
+    }
+
+    /// Gets an iterator of the (`row`, `value`) pairs stored in `col`, in
+    /// row order.
+    ///
+    /// This searches every row, so prefer a `CscMatrix` for column access.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is outside the matrix.
+    fn get_col(&self, col: usize) -> Result<SparseColumnIterator<'_, T>, Error> {
+        Ok(SparseColumnIterator { lane: self.storage.cross_lane(col)? })
+    }
+}
+
+impl<T> IsSparseMatrix<T> for CscMatrix<T> {
+    /// Gets the number of rows.
+    fn rows(&self) -> usize {
+        self.storage.minor
+    }
+
+    /// Gets the number of columns.
+    fn cols(&self) -> usize {
+        self.storage.major
+    }
+
+    /// Gets the number of stored values.
+    fn nnz(&self) -> usize {
+        self.storage.values.len()
+    }
+
+    /// Gets a reference to the value stored at `row`, `col`.
+    ///
+    /// # Returns
+    /// Returns `None` if the value is zero, or if either `row` or `col` is
+    /// outside the matrix.
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.storage.get(col, row)
+    }
+
+    /// Gets an iterator of the (`col`, `value`) pairs stored in `row`, in
+    /// column order.
+    ///
+    /// This searches every column, so prefer a `CsrMatrix` for row access.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `row` is outside the matrix.
+    fn get_row(&self, row: usize) -> Result<SparseRowIterator<'_, T>, Error> {
+        Ok(SparseRowIterator { lane: self.storage.cross_lane(row)? })
+    }
+
+    /// Gets an iterator of the (`row`, `value`) pairs stored in `col`, in
+    /// row order.
+    ///
+    /// # Errors
+    /// Returns `Error::OutOfBounds` if `col` is outside the matrix.
+    fn get_col(&self, col: usize) -> Result<SparseColumnIterator<'_, T>, Error> {
+        Ok(SparseColumnIterator { lane: self.storage.lane(col)? })
+    }
+}
+
+impl<T> Compressed<T> {
+    /// Gets the minor indices and values stored in lane `major`.
+    fn slices(&self, major: usize) -> (&[usize], &[T]) {
+        let range = self.offsets[major]..self.offsets[major + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    fn get(&self, major: usize, minor: usize) -> Option<&T> {
+        if major >= self.major {
+            return None;
+        }
+        let (indices, values) = self.slices(major);
+        indices.binary_search(&minor).ok().map(|position| &values[position])
+    }
+
+    /// Iterates over the values stored in lane `major`.
+    fn lane(&self, major: usize) -> Result<Lane<'_, T>, Error> {
+        check_index(major, self.major)?;
+
+        let (indices, values) = self.slices(major);
+        Ok(Lane::Stored(indices.iter().zip(values)))
+    }
+
+    /// Iterates over the values at index `minor` across every lane.
+    fn cross_lane(&self, minor: usize) -> Result<Lane<'_, T>, Error> {
+        check_index(minor, self.minor)?;
+
+        Ok(Lane::Across { storage: self, minor, major: 0 })
+    }
+
+    /// Iterates over every (`major`, `minor`, `value`) entry in order.
+    fn entries(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        (0..self.major).flat_map(move |major| {
+            let (indices, values) = self.slices(major);
+            indices.iter().zip(values).map(move |(&minor, value)| (major, minor, value))
+        })
+    }
+}
+
+impl<T: Num + Copy> Compressed<T> {
+    /// Builds compressed storage from (`major`, `minor`, `value`) entries in
+    /// any order, summing duplicates and dropping zeros.
+    fn from_entries(major: usize, minor: usize, entries: impl Iterator<Item = (usize, usize, T)>) -> Compressed<T> {
+        let mut entries: Vec<_> = entries.collect();
+        entries.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut storage = Compressed::empty(major, minor);
+        let mut counts = vec![0; major];
+        let mut entries = entries.into_iter().peekable();
+        while let Some((i, j, mut value)) = entries.next() {
+            while let Some(&(_, _, next)) = entries.peek().filter(|&&(k, l, _)| (k, l) == (i, j)) {
+                value = value + next;
+                entries.next();
+            }
+            if !value.is_zero() {
+                storage.indices.push(j);
+                storage.values.push(value);
+                counts[i] += 1;
+            }
+        }
+
+        for (i, count) in counts.into_iter().enumerate() {
+            storage.offsets[i + 1] = storage.offsets[i] + count;
+        }
+        storage
+    }
+
+    /// Builds compressed storage from the nonzero values of dense lanes.
+    fn from_lanes<'a, I>(major: usize, minor: usize, lanes: impl Iterator<Item = I>) -> Compressed<T>
+    where
+        I: Iterator<Item = &'a T>,
+        T: 'a,
+    {
+        let mut storage = Compressed::empty(major, minor);
+        for (i, lane) in lanes.enumerate() {
+            for (j, &value) in lane.enumerate().filter(|(_, value)| !value.is_zero()) {
+                storage.indices.push(j);
+                storage.values.push(value);
+            }
+            storage.offsets[i + 1] = storage.indices.len();
+        }
+        storage
+    }
+
+    fn empty(major: usize, minor: usize) -> Compressed<T> {
+        Compressed { major, minor, offsets: vec![0; major + 1], indices: Vec::new(), values: Vec::new() }
+    }
+
+    /// Swaps the roles of the major and minor dimensions with a counting
+    /// sort, which keeps each new lane sorted.
+    fn transposed(&self) -> Compressed<T> {
+        let mut offsets = vec![0; self.minor + 1];
+        for &j in &self.indices {
+            offsets[j + 1] += 1;
+        }
+        for j in 0..self.minor {
+            offsets[j + 1] += offsets[j];
+        }
+
+        let mut next = offsets.clone();
+        let mut indices = vec![0; self.indices.len()];
+        let mut values = vec![T::zero(); self.values.len()];
+        for (i, j, &value) in self.entries() {
+            indices[next[j]] = i;
+            values[next[j]] = value;
+            next[j] += 1;
+        }
+
+        Compressed { major: self.minor, minor: self.major, offsets, indices, values }
+    }
+
+    /// Converts to a dense matrix, with lanes as rows, or as columns if
+    /// `by_col` is set.
+    fn to_dense(&self, by_col: bool) -> Matrix<T> {
+        let (rows, cols) = if by_col { (self.minor, self.major) } else { (self.major, self.minor) };
+        let mut data = vec![T::zero(); rows * cols];
+        for (i, j, &value) in self.entries() {
+            let (row, col) = if by_col { (j, i) } else { (i, j) };
+            data[row * cols + col] = value;
+        }
+        Matrix::from_vec(rows, cols, data)
+    }
+}
+
+/// The stored values along one row or column of a compressed matrix.
+enum Lane<'a, T> {
+    /// A lane in the compressed direction, stored contiguously.
+    Stored(Zip<slice::Iter<'a, usize>, slice::Iter<'a, T>>),
+    /// A lane across the compressed direction, found by searching each
+    /// compressed lane in turn.
+    Across { storage: &'a Compressed<T>, minor: usize, major: usize },
+}
+
+impl<'a, T> Iterator for Lane<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Lane::Stored(iter) => iter.next().map(|(&index, value)| (index, value)),
+            Lane::Across { storage, minor, major } => {
+                while *major < storage.major {
+                    let index = *major;
+                    *major += 1;
+                    if let Some(value) = storage.get(index, *minor) {
+                        return Some((index, value));
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// An iterator over the (`col`, `value`) pairs stored in a row of a sparse
+/// matrix.
+pub struct SparseRowIterator<'a, T> {
+    lane: Lane<'a, T>,
+}
+
+impl<'a, T> Iterator for SparseRowIterator<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lane.next()
+    }
+}
+
+/// An iterator over the (`row`, `value`) pairs stored in a column of a
+/// sparse matrix.
+pub struct SparseColumnIterator<'a, T> {
+    lane: Lane<'a, T>,
+}
+
+impl<'a, T> Iterator for SparseColumnIterator<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lane.next()
+    }
+}
@@ -0,0 +1,206 @@
+use std::ops::Mul;
+
+use num_traits::Num;
+
+use super::{Compressed, CscMatrix, CsrMatrix, IsSparseMatrix};
+use crate::data_structures::matrix::{IsMatrix, Matrix};
+use crate::error::Error;
+
+impl<T: Num + Copy> CsrMatrix<T> {
+    /// Multiplies the matrix by the column vector `x`.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `x` does not have one value per
+    /// column.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::data_structures::sparse::*;
+    /// # fn main() {
+    /// let csr = CsrMatrix::from_dense(&matrix![[1, 0, 2], [0, 3, 0]]);
+    /// assert_eq!(csr.mul_vec(&[1, 2, 3]).unwrap(), [7, 6]);
+    /// assert!(csr.mul_vec(&[1, 2]).is_err());
+    /// # }
+    /// ```
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>, Error> {
+        check_vec(self.cols(), x)?;
+
+        Ok((0..self.rows())
+            .map(|row| {
+                let (cols, values) = self.storage.slices(row);
+                cols.iter().zip(values).fold(T::zero(), |sum, (&col, &value)| sum + value * x[col])
+            })
+            .collect())
+    }
+
+    /// Multiplies the matrix by a dense matrix, giving a dense matrix.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `rhs` does not have as many rows
+    /// as this matrix has columns.
+    pub fn mul_dense(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        check_product((self.rows(), self.cols()), (rhs.rows(), rhs.cols()))?;
+
+        Ok(dense_product(self.rows(), self.storage.entries(), rhs))
+    }
+
+    /// Multiplies the matrix by another sparse matrix with Gustavson's
+    /// algorithm, which touches only the nonzero products.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `rhs` does not have as many rows
+    /// as this matrix has columns.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::data_structures::sparse::*;
+    /// # fn main() {
+    /// let a = CsrMatrix::from_dense(&matrix![[1, 0], [0, 2], [3, 0]]);
+    /// let b = CsrMatrix::from_dense(&matrix![[0, 4, 0], [5, 0, 0]]);
+    ///
+    /// assert_eq!((&a * &b).to_dense(), matrix![[0, 4, 0], [10, 0, 0], [0, 12, 0]]);
+    /// assert_eq!((&b * &a).to_dense(), matrix![[0, 8], [5, 0]]);
+    /// # }
+    /// ```
+    pub fn mul_sparse(&self, rhs: &CsrMatrix<T>) -> Result<CsrMatrix<T>, Error> {
+        check_product((self.rows(), self.cols()), (rhs.rows(), rhs.cols()))?;
+
+        Ok(CsrMatrix { storage: self.storage.product(&rhs.storage) })
+    }
+}
+
+impl<T: Num + Copy> CscMatrix<T> {
+    /// Multiplies the matrix by the column vector `x`.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `x` does not have one value per
+    /// column.
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>, Error> {
+        check_vec(self.cols(), x)?;
+
+        let mut product = vec![T::zero(); self.rows()];
+        for (col, row, &value) in self.storage.entries() {
+            product[row] = product[row] + value * x[col];
+        }
+        Ok(product)
+    }
+
+    /// Multiplies the matrix by a dense matrix, giving a dense matrix.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `rhs` does not have as many rows
+    /// as this matrix has columns.
+    pub fn mul_dense(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, Error> {
+        check_product((self.rows(), self.cols()), (rhs.rows(), rhs.cols()))?;
+
+        let entries = self.storage.entries().map(|(col, row, value)| (row, col, value));
+        Ok(dense_product(self.rows(), entries, rhs))
+    }
+
+    /// Multiplies the matrix by another sparse matrix with Gustavson's
+    /// algorithm, which touches only the nonzero products.
+    ///
+    /// # Errors
+    /// Returns `Error::DimensionMismatch` if `rhs` does not have as many rows
+    /// as this matrix has columns.
+    pub fn mul_sparse(&self, rhs: &CscMatrix<T>) -> Result<CscMatrix<T>, Error> {
+        check_product((self.rows(), self.cols()), (rhs.rows(), rhs.cols()))?;
+
+        // Column-major storage of A is row-major storage of A^T, and
+        // (AB)^T = B^T A^T.
+        Ok(CscMatrix { storage: rhs.storage.product(&self.storage) })
+    }
+}
+
+impl<T: Num + Copy> Compressed<T> {
+    /// Multiplies row-major storage by row-major storage, accumulating each
+    /// output lane in a dense scratch lane.
+    fn product(&self, rhs: &Compressed<T>) -> Compressed<T> {
+        let mut product = Compressed::empty(self.major, rhs.minor);
+        let mut scratch = vec![T::zero(); rhs.minor];
+        let mut occupied = vec![false; rhs.minor];
+        let mut pattern = Vec::new();
+
+        for i in 0..self.major {
+            let (ks, a_values) = self.slices(i);
+            for (&k, &a) in ks.iter().zip(a_values) {
+                let (js, b_values) = rhs.slices(k);
+                for (&j, &b) in js.iter().zip(b_values) {
+                    if !occupied[j] {
+                        occupied[j] = true;
+                        pattern.push(j);
+                    }
+                    scratch[j] = scratch[j] + a * b;
+                }
+            }
+
+            pattern.sort_unstable();
+            for j in pattern.drain(..) {
+                if !scratch[j].is_zero() {
+                    product.indices.push(j);
+                    product.values.push(scratch[j]);
+                }
+                scratch[j] = T::zero();
+                occupied[j] = false;
+            }
+            product.offsets[i + 1] = product.indices.len();
+        }
+
+        product
+    }
+}
+
+/// Multiplies the sparse matrix with the given (`row`, `col`, `value`)
+/// entries by the dense matrix `rhs`.
+fn dense_product<'a, T: Num + Copy + 'a>(
+    rows: usize,
+    entries: impl Iterator<Item = (usize, usize, &'a T)>,
+    rhs: &Matrix<T>,
+) -> Matrix<T> {
+    let cols = rhs.cols();
+    let mut data = vec![T::zero(); rows * cols];
+    for (row, k, &value) in entries {
+        let out = &mut data[row * cols..(row + 1) * cols];
+        for (out, &b) in out.iter_mut().zip(rhs.get_row(k).unwrap()) {
+            *out = *out + value * b;
+        }
+    }
+    Matrix::from_vec(rows, cols, data)
+}
+
+fn check_product(lhs: (usize, usize), rhs: (usize, usize)) -> Result<(), Error> {
+    if lhs.1 != rhs.0 {
+        return Err(Error::DimensionMismatch { expected: (lhs.1, rhs.1), found: rhs });
+    }
+    Ok(())
+}
+
+fn check_vec<T>(cols: usize, x: &[T]) -> Result<(), Error> {
+    if x.len() != cols {
+        return Err(Error::DimensionMismatch { expected: (cols, 1), found: (x.len(), 1) });
+    }
+    Ok(())
+}
+
+/// Implements `*` between borrowed sparse and dense operands by delegating
+/// to a checked method, panicking on mismatched dimensions.
+macro_rules! impl_mul {
+    ($Lhs:ident, $Rhs:ident, $Output:ident, $checked:ident) => {
+        impl<T: Num + Copy> Mul<&$Rhs<T>> for &$Lhs<T> {
+            type Output = $Output<T>;
+
+            fn mul(self, rhs: &$Rhs<T>) -> $Output<T> {
+                self.$checked(rhs).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+    };
+}
+
+impl_mul!(CsrMatrix, Matrix, Matrix, mul_dense);
+impl_mul!(CsrMatrix, CsrMatrix, CsrMatrix, mul_sparse);
+impl_mul!(CscMatrix, Matrix, Matrix, mul_dense);
+impl_mul!(CscMatrix, CscMatrix, CscMatrix, mul_sparse);
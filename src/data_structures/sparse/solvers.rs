@@ -0,0 +1,500 @@
+use super::{CscMatrix, CsrMatrix};
+use crate::data_structures::matrix::{IsMatrix, Matrix};
+use crate::error::Error;
+
+/// A square or rectangular linear map *A* that the iterative solvers can
+/// apply to vectors, implemented for dense and sparse matrices.
+///
+/// The solvers only need products with *A*, single rows of *A* and its
+/// diagonal, so a matrix-free operator can implement this directly.
+pub trait IsLinearOperator {
+    /// Gets the (`rows`, `cols`) shape of the operator.
+    fn shape(&self) -> (usize, usize);
+
+    /// Gets the dot product of row `row` with `x`.
+    fn row_dot(&self, row: usize, x: &[f64]) -> f64;
+
+    /// Gets the main diagonal, with one value per row.
+    fn diagonal(&self) -> Vec<f64>;
+
+    /// Multiplies the operator by the vector `x`, which has one value per
+    /// column.
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        (0..self.shape().0).map(|row| self.row_dot(row, x)).collect()
+    }
+
+    /// Solves *Ax = b* for a symmetric positive definite operator by the
+    /// preconditioned conjugate gradient method.
+    ///
+    /// Each iteration minimises the *A*-norm of the error over a Krylov
+    /// subspace one dimension larger than the last, so in exact arithmetic
+    /// the method finishes in at most *n* iterations.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the operator is not square, or `b`
+    ///   does not have one value per row
+    /// - `Error::NotPositiveDefinite`: If a search direction *p* with
+    ///   *p*<sup>T</sup>*Ap* ≤ 0 was found, with `index` the iteration it was
+    ///   found in
+    /// - `Error::NotConverged`: If the residual did not meet the tolerance
+    ///   within `options.max_iterations`
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::data_structures::sparse::*;
+    /// # fn main() {
+    /// let a = CsrMatrix::from_dense(&matrix![[4.0, 1.0], [1.0, 3.0]]);
+    /// let options = SolverOptions::default();
+    /// let preconditioner = JacobiPreconditioner::new(&a).unwrap();
+    ///
+    /// let result = a.conjugate_gradient(&[1.0, 2.0], &options, &preconditioner).unwrap();
+    /// assert!((result.solution()[0] - 1.0 / 11.0).abs() < 1e-10);
+    /// assert!((result.solution()[1] - 7.0 / 11.0).abs() < 1e-10);
+    /// assert!(result.iterations() <= 2);
+    /// # }
+    /// ```
+    fn conjugate_gradient<P: IsPreconditioner>(
+        &self,
+        b: &[f64],
+        options: &SolverOptions,
+        preconditioner: &P,
+    ) -> Result<IterativeSolution, Error> {
+        let mut monitor = Monitor::new(self, b, options)?;
+        let mut x = vec![0.0; b.len()];
+        let mut r = b.to_vec();
+        let mut z = preconditioner.precondition(&r);
+        let mut p = z.clone();
+        let mut rz = dot(&r, &z);
+
+        while !monitor.converged(&r)? {
+            let ap = self.apply(&p);
+            let pap = dot(&p, &ap);
+            if pap <= 0.0 {
+                return Err(Error::NotPositiveDefinite { index: monitor.iterations });
+            }
+
+            let alpha = rz / pap;
+            axpy(&mut x, alpha, &p);
+            axpy(&mut r, -alpha, &ap);
+
+            z = preconditioner.precondition(&r);
+            let rz_next = dot(&r, &z);
+            let beta = rz_next / rz;
+            rz = rz_next;
+            for (p, z) in p.iter_mut().zip(&z) {
+                *p = z + beta * *p;
+            }
+        }
+
+        Ok(monitor.finish(x))
+    }
+
+    /// Solves *Ax = b* by Jacobi iteration, updating every value of *x*
+    /// from the previous iterate.
+    ///
+    /// Converges for strictly diagonally dominant operators, among others.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the operator is not square, or `b`
+    ///   does not have one value per row
+    /// - `Error::Singular`: If the diagonal has a zero
+    /// - `Error::NotConverged`: If the residual did not meet the tolerance
+    ///   within `options.max_iterations`
+    fn jacobi(&self, b: &[f64], options: &SolverOptions) -> Result<IterativeSolution, Error> {
+        let mut monitor = Monitor::new(self, b, options)?;
+        let inverse_diagonal = JacobiPreconditioner::new(self)?;
+        let mut x = vec![0.0; b.len()];
+        let mut r = b.to_vec();
+
+        while !monitor.converged(&r)? {
+            axpy(&mut x, 1.0, &inverse_diagonal.precondition(&r));
+            r = residual(self, b, &x);
+        }
+
+        Ok(monitor.finish(x))
+    }
+
+    /// Solves *Ax = b* by Gauss-Seidel iteration, updating each value of
+    /// *x* in place from the values already updated in the same sweep.
+    ///
+    /// Converges for symmetric positive definite and strictly diagonally
+    /// dominant operators, usually about twice as fast as Jacobi iteration.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the operator is not square, or `b`
+    ///   does not have one value per row
+    /// - `Error::Singular`: If the diagonal has a zero
+    /// - `Error::NotConverged`: If the residual did not meet the tolerance
+    ///   within `options.max_iterations`
+    fn gauss_seidel(&self, b: &[f64], options: &SolverOptions) -> Result<IterativeSolution, Error> {
+        let mut monitor = Monitor::new(self, b, options)?;
+        let diagonal = self.diagonal();
+        if diagonal.contains(&0.0) {
+            return Err(Error::Singular);
+        }
+        let mut x = vec![0.0; b.len()];
+        let mut r = b.to_vec();
+
+        while !monitor.converged(&r)? {
+            for (i, d) in diagonal.iter().enumerate() {
+                x[i] += (b[i] - self.row_dot(i, &x)) / d;
+            }
+            r = residual(self, b, &x);
+        }
+
+        Ok(monitor.finish(x))
+    }
+
+    /// Solves *Ax = b* for a general square operator by the restarted
+    /// generalised minimal residual method, GMRES(*m*), with *m* given by
+    /// `options.restart`.
+    ///
+    /// Each iteration extends an orthonormal Krylov basis by one vector and
+    /// minimises the residual over it. The basis is discarded every *m*
+    /// iterations to bound memory use. The preconditioner is applied on the
+    /// right, so the residuals recorded are those of the original system.
+    ///
+    /// # Errors
+    /// - `Error::DimensionMismatch`: If the operator is not square, or `b`
+    ///   does not have one value per row
+    /// - `Error::Singular`: If the operator was found to be singular
+    /// - `Error::NotConverged`: If the residual did not meet the tolerance
+    ///   within `options.max_iterations`
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::matrix::*;
+    /// # use crate::algorithms::data_structures::sparse::*;
+    /// # fn main() {
+    /// // Not symmetric, so conjugate gradients do not apply
+    /// let a = matrix![[2.0, 1.0, 0.0], [-1.0, 3.0, 1.0], [0.0, 2.0, 4.0]];
+    /// let result = a.gmres(&[3.0, 3.0, 6.0], &SolverOptions::default(), &IdentityPreconditioner).unwrap();
+    ///
+    /// for value in result.solution() {
+    ///     assert!((value - 1.0).abs() < 1e-9);
+    /// }
+    /// assert!(result.residual() < 1e-9);
+    /// # }
+    /// ```
+    fn gmres<P: IsPreconditioner>(
+        &self,
+        b: &[f64],
+        options: &SolverOptions,
+        preconditioner: &P,
+    ) -> Result<IterativeSolution, Error> {
+        let mut monitor = Monitor::new(self, b, options)?;
+        let restart = options.restart.max(1);
+        let mut x = vec![0.0; b.len()];
+        let mut r = b.to_vec();
+
+        while !monitor.converged(&r)? {
+            let beta = norm(&r);
+            let mut basis = vec![r.iter().map(|r| r / beta).collect::<Vec<_>>()];
+            // Columns of the Hessenberg matrix, reduced to upper triangular
+            // form by the Givens rotations (cs, sn) as they are added.
+            let mut columns: Vec<Vec<f64>> = Vec::with_capacity(restart);
+            let mut rotations: Vec<(f64, f64)> = Vec::with_capacity(restart);
+            let mut g = vec![beta];
+
+            for j in 0..restart {
+                let mut w = self.apply(&preconditioner.precondition(&basis[j]));
+                let mut h: Vec<f64> = Vec::with_capacity(j + 2);
+                for v in &basis {
+                    let h_ij = dot(&w, v);
+                    axpy(&mut w, -h_ij, v);
+                    h.push(h_ij);
+                }
+                let h_next = norm(&w);
+                h.push(h_next);
+
+                for (i, &(cs, sn)) in rotations.iter().enumerate() {
+                    let (a, b) = (h[i], h[i + 1]);
+                    h[i] = cs * a + sn * b;
+                    h[i + 1] = cs * b - sn * a;
+                }
+                let d = h[j].hypot(h[j + 1]);
+                if d == 0.0 {
+                    return Err(Error::Singular);
+                }
+                let (cs, sn) = (h[j] / d, h[j + 1] / d);
+                h[j] = d;
+                h.truncate(j + 1);
+                rotations.push((cs, sn));
+                columns.push(h);
+                g.push(-sn * g[j]);
+                g[j] *= cs;
+
+                if !monitor.continues(g[j + 1].abs()) || h_next == 0.0 || j + 1 == restart {
+                    break;
+                }
+                basis.push(w.iter().map(|w| w / h_next).collect());
+            }
+
+            // Back substitute for the weights of the basis vectors.
+            let k = columns.len();
+            let mut y = g[..k].to_vec();
+            for i in (0..k).rev() {
+                y[i] /= columns[i][i];
+                let y_i = y[i];
+                for (y_l, r_li) in y[..i].iter_mut().zip(&columns[i]) {
+                    *y_l -= r_li * y_i;
+                }
+            }
+            let mut update = vec![0.0; b.len()];
+            for (y, v) in y.iter().zip(&basis) {
+                axpy(&mut update, *y, v);
+            }
+            axpy(&mut x, 1.0, &preconditioner.precondition(&update));
+            r = residual(self, b, &x);
+            monitor.restart();
+        }
+
+        Ok(monitor.finish(x))
+    }
+}
+
+/// A preconditioner *M* ≈ *A*<sup>-1</sup>, applied to residuals to speed
+/// up the conjugate gradient and GMRES solvers.
+pub trait IsPreconditioner {
+    /// Applies the preconditioner to `residual`.
+    fn precondition(&self, residual: &[f64]) -> Vec<f64>;
+}
+
+/// The identity preconditioner, which leaves residuals unchanged.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IdentityPreconditioner;
+
+impl IsPreconditioner for IdentityPreconditioner {
+    fn precondition(&self, residual: &[f64]) -> Vec<f64> {
+        residual.to_vec()
+    }
+}
+
+/// The diagonal (Jacobi) preconditioner, which divides each residual value
+/// by the matching diagonal value of the operator.
+///
+/// It is cheap, and effective when the diagonal varies widely in scale.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JacobiPreconditioner {
+    inverse_diagonal: Vec<f64>,
+}
+
+impl JacobiPreconditioner {
+    /// Constructs a `JacobiPreconditioner` from the diagonal of `operator`.
+    ///
+    /// # Errors
+    /// Returns `Error::Singular` if the diagonal has a zero.
+    pub fn new<A: IsLinearOperator + ?Sized>(operator: &A) -> Result<JacobiPreconditioner, Error> {
+        let diagonal = operator.diagonal();
+        if diagonal.contains(&0.0) {
+            return Err(Error::Singular);
+        }
+
+        Ok(JacobiPreconditioner { inverse_diagonal: diagonal.iter().map(|d| 1.0 / d).collect() })
+    }
+}
+
+impl IsPreconditioner for JacobiPreconditioner {
+    fn precondition(&self, residual: &[f64]) -> Vec<f64> {
+        residual.iter().zip(&self.inverse_diagonal).map(|(r, d)| r * d).collect()
+    }
+}
+
+/// Stopping criteria for the iterative solvers.
+///
+/// A solver stops once the residual *b - Ax* satisfies
+/// ‖*b - Ax*‖ ≤ `tolerance` ‖*b*‖, starting from *x* = 0.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SolverOptions {
+    /// The relative residual to reach. Defaults to `1e-10`.
+    pub tolerance: f64,
+    /// The number of iterations to give up after. Defaults to 1000.
+    pub max_iterations: usize,
+    /// The number of iterations between GMRES restarts. Defaults to 30.
+    pub restart: usize,
+}
+
+impl Default for SolverOptions {
+    fn default() -> Self {
+        SolverOptions { tolerance: 1e-10, max_iterations: 1000, restart: 30 }
+    }
+}
+
+/// The solution found by an iterative solver, with convergence
+/// diagnostics.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IterativeSolution {
+    solution: Vec<f64>,
+    iterations: usize,
+    residuals: Vec<f64>,
+}
+
+impl IterativeSolution {
+    /// Gets the solution *x*.
+    pub fn solution(&self) -> &[f64] {
+        &self.solution
+    }
+
+    /// Gets the number of iterations taken.
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// Gets the residual norm ‖*b - Ax*‖ before the first iteration and
+    /// after each iteration.
+    ///
+    /// For GMRES, the norms within a restart cycle are the estimates the
+    /// method minimises, which match the true norms up to rounding error.
+    pub fn residuals(&self) -> &[f64] {
+        &self.residuals
+    }
+
+    /// Gets the final residual norm ‖*b - Ax*‖.
+    pub fn residual(&self) -> f64 {
+        self.residuals[self.residuals.len() - 1]
+    }
+}
+
+/// Tracks the residual history and stopping criteria of a solver.
+struct Monitor {
+    threshold: f64,
+    max_iterations: usize,
+    iterations: usize,
+    residuals: Vec<f64>,
+}
+
+impl Monitor {
+    fn new<A: IsLinearOperator + ?Sized>(operator: &A, b: &[f64], options: &SolverOptions) -> Result<Monitor, Error> {
+        let (rows, cols) = operator.shape();
+        if rows != cols {
+            return Err(Error::DimensionMismatch { expected: (rows, rows), found: (rows, cols) });
+        }
+        if b.len() != rows {
+            return Err(Error::DimensionMismatch { expected: (rows, 1), found: (b.len(), 1) });
+        }
+
+        Ok(Monitor {
+            threshold: options.tolerance * norm(b),
+            max_iterations: options.max_iterations,
+            iterations: 0,
+            residuals: Vec::new(),
+        })
+    }
+
+    /// Records the residual `r` of the latest iterate, and checks if it
+    /// meets the tolerance.
+    ///
+    /// # Errors
+    /// Returns `Error::NotConverged` if it does not, and no iterations are
+    /// left.
+    fn converged(&mut self, r: &[f64]) -> Result<bool, Error> {
+        if !self.residuals.is_empty() {
+            self.iterations += 1;
+        }
+        let residual = norm(r);
+        self.residuals.push(residual);
+
+        if residual <= self.threshold {
+            Ok(true)
+        } else if self.iterations >= self.max_iterations {
+            Err(Error::NotConverged { iterations: self.iterations })
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Records an estimated residual norm from inside a GMRES cycle, and
+    /// checks if iteration should continue.
+    fn continues(&mut self, estimate: f64) -> bool {
+        self.iterations += 1;
+        self.residuals.push(estimate);
+        estimate > self.threshold && self.iterations < self.max_iterations
+    }
+
+    /// Prepares to record the true residual at the end of a GMRES cycle in
+    /// place of the last estimate.
+    fn restart(&mut self) {
+        self.residuals.pop();
+        self.iterations -= 1;
+    }
+
+    fn finish(self, solution: Vec<f64>) -> IterativeSolution {
+        IterativeSolution { solution, iterations: self.iterations, residuals: self.residuals }
+    }
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(x, y)| x * y).sum()
+}
+
+fn norm(x: &[f64]) -> f64 {
+    dot(x, x).sqrt()
+}
+
+/// Adds `alpha * x` to `y`.
+fn axpy(y: &mut [f64], alpha: f64, x: &[f64]) {
+    for (y, x) in y.iter_mut().zip(x) {
+        *y += alpha * x;
+    }
+}
+
+/// Gets the residual *b - Ax*.
+fn residual<A: IsLinearOperator + ?Sized>(operator: &A, b: &[f64], x: &[f64]) -> Vec<f64> {
+    b.iter().zip(operator.apply(x)).map(|(b, ax)| b - ax).collect()
+}
+
+impl IsLinearOperator for Matrix<f64> {
+    fn shape(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn row_dot(&self, row: usize, x: &[f64]) -> f64 {
+        self.get_row(row).unwrap().zip(x).map(|(a, x)| a * x).sum()
+    }
+
+    fn diagonal(&self) -> Vec<f64> {
+        (0..self.rows()).map(|i| self.get(i, i).copied().unwrap_or(0.0)).collect()
+    }
+}
+
+impl IsLinearOperator for CsrMatrix<f64> {
+    fn shape(&self) -> (usize, usize) {
+        (self.storage.major, self.storage.minor)
+    }
+
+    fn row_dot(&self, row: usize, x: &[f64]) -> f64 {
+        let (cols, values) = self.storage.slices(row);
+        cols.iter().zip(values).map(|(&col, value)| value * x[col]).sum()
+    }
+
+    fn diagonal(&self) -> Vec<f64> {
+        (0..self.storage.major).map(|i| self.storage.get(i, i).copied().unwrap_or(0.0)).collect()
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.mul_vec(x).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Rows of a `CscMatrix` are found by searching every column, so prefer a
+/// `CsrMatrix` for the Gauss-Seidel solver.
+impl IsLinearOperator for CscMatrix<f64> {
+    fn shape(&self) -> (usize, usize) {
+        (self.storage.minor, self.storage.major)
+    }
+
+    fn row_dot(&self, row: usize, x: &[f64]) -> f64 {
+        (0..self.storage.major).filter_map(|col| self.storage.get(col, row).map(|value| value * x[col])).sum()
+    }
+
+    fn diagonal(&self) -> Vec<f64> {
+        (0..self.storage.minor).map(|i| self.storage.get(i, i).copied().unwrap_or(0.0)).collect()
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.mul_vec(x).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
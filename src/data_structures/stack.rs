@@ -1,9 +1,14 @@
-use std::error::Error;
+use crate::data_structures::queue::IsQueue;
+use crate::error::Error;
+
+pub mod concurrent;
+
+pub use concurrent::{IsConcurrentStack, TreiberStack};
 
 /// A simple stack (LIFO) implementation with a growable size and no capacity limit.
 ///
 /// # Type parameters
-/// - `T`: Generic type that implements the `Clone` trait.
+/// - `T`: Generic type of the elements in the stack.
 ///
 /// # Examples
 ///
@@ -32,13 +37,13 @@ use std::error::Error;
 /// # }
 /// ```
 /// 
-#[derive(Debug)]
-pub struct Stack<T: Clone> {
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stack<T> {
     stack: Vec<T>
 }
 
 /// Defines methods expected on a stack data structure
-pub trait IsStack<T: Clone> {
+pub trait IsStack<T> {
     /// Adds a new value to a stack.
     ///
     /// # Parameters
@@ -47,14 +52,14 @@ pub trait IsStack<T: Clone> {
     /// # Returns
     /// - `Ok(_)`: If the element add was successful.
     ///     - `Some(T)`: If adding an element resulted in the removal of an
-    ///         existing one (in the case of a circular buffer, for instance)
+    ///       existing one (in the case of a circular buffer, for instance)
     ///     - `None`: Adding an element did not return any value
-    /// - `Error`: If the element add was unsuccessful
+    /// - `Err(Error::Full)`: If the element add was unsuccessful
     ///
     /// # Errors
     /// Attempting to add an element to a full stack that does not allow for
-    /// overflow will return an error.
-    fn add(&mut self, value: T) -> Result<Option<T>, Box<dyn Error>>;
+    /// overflow will return `Error::Full`.
+    fn add(&mut self, value: T) -> Result<Option<T>, Error>;
 
     /// Removes an element from the stack and returns it.
     ///
@@ -63,21 +68,33 @@ pub trait IsStack<T: Clone> {
     ///
     /// # Returns
     /// - `Ok(T)`: The oldest value in the stack
-    /// - `Error`
+    /// - `Err(Error::Empty)`: If the stack is empty
     ///
     /// # Errors
-    /// Returns an error if the stack is empty.
-    fn remove(&mut self) -> Result<T, Box<dyn Error>>;
+    /// Returns `Error::Empty` if the stack is empty.
+    fn remove(&mut self) -> Result<T, Error>;
 
-    /// Reads the end of the stack.
+    /// Gets a reference to the next element scheduled for removal.
     ///
     /// # Returns
-    /// - `Ok(T)`: The oldest value in the stack
-    /// - `Error`
+    /// - `Ok(&T)`: The newest value in the stack
+    /// - `Err(Error::Empty)`: If the stack is empty
     ///
     /// # Errors
-    /// Returns an error if the stack is empty.
-    fn read(&self) -> Result<T, Box<dyn Error>>;
+    /// Returns `Error::Empty` if the stack is empty.
+    fn peek(&self) -> Result<&T, Error>;
+
+    /// Reads the end of the stack, returning a clone of the value `peek`
+    /// refers to.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the stack is empty.
+    fn read(&self) -> Result<T, Error>
+    where
+        T: Clone,
+    {
+        self.peek().cloned()
+    }
 
     /// Gets the size of the stack.
     ///
@@ -86,9 +103,24 @@ pub trait IsStack<T: Clone> {
     /// values when specified, which means that the `size` of a stack with
     /// default values should always be equal to its `capacity`
     fn size(&self) -> usize;
+
+    /// Returns `true` if the stack contains no elements.
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+
+    /// Gets the number of elements that can be added before `add` returns
+    /// `Error::Full`.
+    ///
+    /// # Returns
+    /// - `Some(usize)`: The remaining space in a bounded stack
+    /// - `None`: If the stack never rejects an element
+    fn remaining_capacity(&self) -> Option<usize> {
+        None
+    }
 }
 
-impl<T: Clone> Stack<T> {
+impl<T> Stack<T> {
     /// Creates a new simple `stack`.
     ///
     /// # Returns
@@ -104,9 +136,49 @@ impl<T: Clone> Stack<T> {
     pub fn new() -> Stack<T> {
         Stack { stack: vec![] }
     }
+
+    /// Iterates over the `stack` without removing any elements.
+    ///
+    /// # Returns
+    /// Returns an iterator visiting the elements from the top of the stack
+    /// (the newest element) to the bottom, in the order `remove` would
+    /// return them.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// # fn main() {
+    /// let stack = stack![1, 2, 3];
+    /// let values: Vec<&isize> = stack.iter().collect();
+    ///
+    /// assert_eq!(values, [&3, &2, &1]);
+    /// assert_eq!(stack.size(), 3);
+    /// # }
+    /// ```
+    pub fn iter(&self) -> std::iter::Rev<std::slice::Iter<'_, T>> {
+        self.stack.iter().rev()
+    }
+
+    /// Removes every element from the `stack`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// # fn main() {
+    /// let mut stack = stack![1, 2, 3];
+    /// stack.clear();
+    ///
+    /// assert!(stack.is_empty());
+    /// # }
+    /// ```
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
 }
 
-impl<T: Clone> Default for Stack<T> {
+impl<T> Default for Stack<T> {
     /// Initializes a `Default` `stack`.
     ///
     /// # Returns
@@ -123,7 +195,60 @@ impl<T: Clone> Default for Stack<T> {
     }
 }
 
-impl<T: Clone> IsStack<T> for Stack<T> {
+impl<T> FromIterator<T> for Stack<T> {
+    /// Builds a `stack` by adding each element in turn, so the last element
+    /// yielded by the iterator ends up on top.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// let stack: Stack<isize> = (1..=3).collect();
+    /// assert_eq!(stack.peek().unwrap(), &3);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Stack<T> {
+        Stack { stack: iter.into_iter().collect() }
+    }
+}
+
+impl<T> Extend<T> for Stack<T> {
+    /// Adds each element in turn to the top of the `stack`.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.stack.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = std::iter::Rev<std::vec::IntoIter<T>>;
+
+    /// Drains the `stack` in LIFO order, newest element first.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[macro_use] extern crate algorithms;
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// # fn main() {
+    /// let stack = stack![1, 2, 3];
+    /// let values: Vec<isize> = stack.into_iter().collect();
+    ///
+    /// assert_eq!(values, [3, 2, 1]);
+    /// # }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        self.stack.into_iter().rev()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = std::iter::Rev<std::slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IsStack<T> for Stack<T> {
     /// Adds a new element to the end of the `stack`.
     ///
     /// # Parameters
@@ -139,7 +264,7 @@ impl<T: Clone> IsStack<T> for Stack<T> {
     /// assert_eq!(stack.add(42).unwrap(), None);
     /// assert_eq!(stack.size(), 1);
     /// ```
-    fn add(&mut self, value: T) -> Result<Option<T>, Box<dyn Error>> {
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
         self.stack.push(value);
         Ok(None)
     }
@@ -148,35 +273,34 @@ impl<T: Clone> IsStack<T> for Stack<T> {
     ///
     /// # Returns
     /// - `Ok(T)`: The last value in the stack
-    /// - `Error`
+    /// - `Err(Error::Empty)`: If the stack is empty
     ///
     /// # Errors
-    /// Returns an error if the `stack` is empty.
+    /// Returns `Error::Empty` if the `stack` is empty.
     ///
     /// # Examples
     /// ```
     /// # use crate::algorithms::data_structures::stack::*;
+    /// # use crate::algorithms::error::Error;
     /// let mut q: Stack<isize> = Stack::new();
     /// q.add(42);
     /// assert_eq!(q.remove().unwrap(), 42);
     /// assert_eq!(q.size(), 0);
+    ///
+    /// assert_eq!(q.remove(), Err(Error::Empty));
     /// ```
-    fn remove(&mut self) -> Result<T, Box<dyn Error>> {
-        if !self.stack.is_empty() {
-            Ok(self.stack.pop().unwrap())
-        } else {
-            panic!("The stack is empty")
-        }
+    fn remove(&mut self) -> Result<T, Error> {
+        self.stack.pop().ok_or(Error::Empty)
     }
 
-    /// Reads the last value in the `stack`.
+    /// Gets a reference to the last value in the `stack`.
     ///
     /// # Returns
-    /// - `T`: The oldest value in the stack
-    /// - `Error`
+    /// - `Ok(&T)`: The newest value in the stack
+    /// - `Err(Error::Empty)`: If the stack is empty
     ///
     /// # Errors
-    /// Returns an error if the `stack` is empty.
+    /// Returns `Error::Empty` if the `stack` is empty.
     ///
     /// # Examples
     ///
@@ -185,13 +309,10 @@ impl<T: Clone> IsStack<T> for Stack<T> {
     /// let mut stack: Stack<isize> = Stack::new();
     /// stack.add(42);
     /// stack.add(43);
-    /// assert_eq!(stack.read().unwrap(), 43);
+    /// assert_eq!(stack.peek().unwrap(), &43);
     /// ```
-    fn read(&self) -> Result<T, Box<dyn Error>> {
-        match self.stack.last() {
-            Some(val) => Ok(val.clone()),
-            None => panic!("The stack is empty"),
-        }
+    fn peek(&self) -> Result<&T, Error> {
+        self.stack.last().ok_or(Error::Empty)
     }
 
     /// Gets the size of the `stack`.
@@ -213,6 +334,412 @@ impl<T: Clone> IsStack<T> for Stack<T> {
     }
 }
 
+/// A stack (LIFO) with a fixed capacity that rejects elements once full.
+///
+/// Unlike `Stack`, adding to a full `BoundedStack` does not grow the stack;
+/// it returns `Error::Full` and leaves the stack unchanged. This makes it
+/// suitable for applying back-pressure to producers.
+///
+/// # Type parameters
+/// - `T`: Generic type of the elements in the stack.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::stack::*;
+/// # use crate::algorithms::error::Error;
+/// let mut stack = BoundedStack::new(2);
+///
+/// assert_eq!(stack.add(1).unwrap(), None);
+/// assert_eq!(stack.add(2).unwrap(), None);
+/// assert_eq!(stack.add(3), Err(Error::Full));
+///
+/// assert_eq!(stack.remove().unwrap(), 2);
+/// assert_eq!(stack.remaining(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundedStack<T> {
+    stack: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> BoundedStack<T> {
+    /// Creates a new empty `BoundedStack` holding at most `capacity` elements.
+    ///
+    /// # Returns
+    /// Returns an empty `BoundedStack<T>`.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// let stack: BoundedStack<isize> = BoundedStack::new(5);
+    ///
+    /// assert_eq!(stack.size(), 0);
+    /// assert_eq!(stack.capacity(), 5);
+    /// ```
+    pub fn new(capacity: usize) -> BoundedStack<T> {
+        assert!(capacity > 0);
+
+        BoundedStack { stack: Vec::with_capacity(capacity), capacity }
+    }
+
+    /// Gets the maximum number of elements the `BoundedStack` can hold.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns `true` if the `BoundedStack` is at capacity, meaning the next
+    /// `add` will fail.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// let mut stack = BoundedStack::new(1);
+    /// assert!(!stack.is_full());
+    ///
+    /// stack.add(42);
+    /// assert!(stack.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.stack.len() >= self.capacity
+    }
+
+    /// Gets the number of elements that can be added before the
+    /// `BoundedStack` is full.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// let mut stack = BoundedStack::new(3);
+    /// stack.add(42);
+    /// assert_eq!(stack.remaining(), 2);
+    /// ```
+    pub fn remaining(&self) -> usize {
+        self.capacity - self.stack.len()
+    }
+}
+
+impl<T> IsStack<T> for BoundedStack<T> {
+    /// Adds a new element to the end of the `BoundedStack`.
+    ///
+    /// # Parameters
+    /// - `value`: Value to add to the stack
+    ///
+    /// # Returns
+    /// - `Ok(None)`: If the element was added
+    /// - `Err(Error::Full)`: If the stack is at capacity
+    ///
+    /// # Errors
+    /// Returns `Error::Full` if the `BoundedStack` is at capacity. The
+    /// element is not added.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// # use crate::algorithms::error::Error;
+    /// let mut stack = BoundedStack::new(1);
+    /// assert_eq!(stack.add(42).unwrap(), None);
+    /// assert_eq!(stack.add(43), Err(Error::Full));
+    /// ```
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        if self.is_full() {
+            return Err(Error::Full);
+        }
+
+        self.stack.push(value);
+        Ok(None)
+    }
+
+    /// Removes the last value from the `BoundedStack` and returns it.
+    ///
+    /// # Returns
+    /// - `Ok(T)`: The last value in the stack
+    /// - `Err(Error::Empty)`: If the stack is empty
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `BoundedStack` is empty.
+    fn remove(&mut self) -> Result<T, Error> {
+        self.stack.pop().ok_or(Error::Empty)
+    }
+
+    /// Gets a reference to the last value in the `BoundedStack`.
+    ///
+    /// # Returns
+    /// - `Ok(&T)`: The last value in the stack
+    /// - `Err(Error::Empty)`: If the stack is empty
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `BoundedStack` is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// let mut stack = BoundedStack::new(2);
+    /// stack.add(42);
+    /// stack.add(43);
+    /// assert_eq!(stack.peek().unwrap(), &43);
+    /// ```
+    fn peek(&self) -> Result<&T, Error> {
+        self.stack.last().ok_or(Error::Empty)
+    }
+
+    /// Gets the size of the `BoundedStack`.
+    ///
+    /// # Returns
+    /// Returns the number of elements in the stack.
+    fn size(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Gets the number of elements that can be added before the
+    /// `BoundedStack` is full.
+    fn remaining_capacity(&self) -> Option<usize> {
+        Some(self.remaining())
+    }
+}
+
+/// A stack (LIFO) that also answers `min` and `max` queries in O(1).
+///
+/// Alongside each element, the stack records the positions of the smallest
+/// and largest elements at or below it. Since elements are only ever removed
+/// from the top, those positions never go stale, and every operation stays
+/// O(1).
+///
+/// # Type parameters
+/// - `T`: Generic type that implements the `PartialOrd` trait.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::stack::*;
+/// let mut stack = MinMaxStack::new();
+/// for value in [5, 2, 8, 1] {
+///     stack.add(value).unwrap();
+/// }
+///
+/// assert_eq!(stack.min().unwrap(), &1);
+/// assert_eq!(stack.max().unwrap(), &8);
+///
+/// stack.remove().unwrap();
+/// assert_eq!(stack.min().unwrap(), &2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinMaxStack<T: PartialOrd> {
+    stack: Vec<T>,
+    extrema: Vec<(usize, usize)>,
+}
+
+impl<T: PartialOrd> MinMaxStack<T> {
+    /// Creates a new empty `MinMaxStack`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// let stack: MinMaxStack<isize> = MinMaxStack::new();
+    /// assert_eq!(stack.size(), 0);
+    /// ```
+    pub fn new() -> MinMaxStack<T> {
+        MinMaxStack { stack: vec![], extrema: vec![] }
+    }
+
+    /// Gets a reference to the smallest element in the stack.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the stack is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// # use crate::algorithms::error::Error;
+    /// let mut stack = MinMaxStack::new();
+    /// assert_eq!(stack.min(), Err(Error::Empty));
+    ///
+    /// stack.add(3).unwrap();
+    /// stack.add(1).unwrap();
+    /// assert_eq!(stack.min().unwrap(), &1);
+    /// ```
+    pub fn min(&self) -> Result<&T, Error> {
+        let (min, _) = self.extrema.last().ok_or(Error::Empty)?;
+        Ok(&self.stack[*min])
+    }
+
+    /// Gets a reference to the largest element in the stack.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the stack is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::*;
+    /// let mut stack = MinMaxStack::new();
+    /// stack.add(3).unwrap();
+    /// stack.add(1).unwrap();
+    /// assert_eq!(stack.max().unwrap(), &3);
+    /// ```
+    pub fn max(&self) -> Result<&T, Error> {
+        let (_, max) = self.extrema.last().ok_or(Error::Empty)?;
+        Ok(&self.stack[*max])
+    }
+}
+
+impl<T: PartialOrd> Default for MinMaxStack<T> {
+    /// Initializes a `Default` `MinMaxStack`.
+    fn default() -> MinMaxStack<T> {
+        MinMaxStack::new()
+    }
+}
+
+impl<T: PartialOrd> IsStack<T> for MinMaxStack<T> {
+    /// Adds a new element to the end of the `MinMaxStack`.
+    ///
+    /// # Returns
+    /// `None` as the element addition should always be successful.
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        let top = self.stack.len();
+        let extrema = match self.extrema.last() {
+            Some(&(min, max)) => (
+                if value < self.stack[min] { top } else { min },
+                if value > self.stack[max] { top } else { max },
+            ),
+            None => (top, top),
+        };
+
+        self.stack.push(value);
+        self.extrema.push(extrema);
+        Ok(None)
+    }
+
+    /// Removes the last value from the `MinMaxStack` and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `MinMaxStack` is empty.
+    fn remove(&mut self) -> Result<T, Error> {
+        self.extrema.pop();
+        self.stack.pop().ok_or(Error::Empty)
+    }
+
+    /// Gets a reference to the last value in the `MinMaxStack`.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `MinMaxStack` is empty.
+    fn peek(&self) -> Result<&T, Error> {
+        self.stack.last().ok_or(Error::Empty)
+    }
+
+    /// Gets the size of the `MinMaxStack`.
+    fn size(&self) -> usize {
+        self.stack.len()
+    }
+}
+
+/// A stack (LIFO) built from a single queue.
+///
+/// The queue is kept in LIFO order: after each new element is added to the
+/// back, every older element is removed from the front and added again
+/// behind it, so the newest element is always at the front. This makes `add`
+/// O(n) while `remove` and `peek` are O(1). Unlike `TwoStackQueue`, the cost
+/// cannot be amortised away, since every `add` has to rotate past every
+/// element already in the stack.
+///
+/// The stack works with any `IsQueue` implementation whose `add` keeps all
+/// existing elements, such as `Queue` or `BoundedQueue`.
+///
+/// # Type parameters
+/// - `Q`: Backing queue type that implements the `IsQueue` trait.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::stack::*;
+/// # use crate::algorithms::data_structures::queue::Queue;
+/// let mut stack: QueueStack<Queue<isize>> = QueueStack::new();
+/// stack.add(1).unwrap();
+/// stack.add(2).unwrap();
+/// stack.add(3).unwrap();
+///
+/// assert_eq!(stack.remove().unwrap(), 3);
+/// assert_eq!(stack.peek().unwrap(), &2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QueueStack<Q> {
+    queue: Q,
+}
+
+impl<Q: Default> QueueStack<Q> {
+    /// Creates a new empty `QueueStack` with a default backing queue.
+    pub fn new() -> QueueStack<Q> {
+        QueueStack { queue: Q::default() }
+    }
+}
+
+impl<Q> QueueStack<Q> {
+    /// Creates a new `QueueStack` on top of the given queue, which is
+    /// expected to be empty.
+    pub fn from_queue(queue: Q) -> QueueStack<Q> {
+        QueueStack { queue }
+    }
+
+    /// Consumes the stack, returning the backing queue with the newest
+    /// element at the front.
+    pub fn into_queue(self) -> Q {
+        self.queue
+    }
+}
+
+impl<Q: Default> Default for QueueStack<Q> {
+    /// Initializes a `Default` `QueueStack`.
+    fn default() -> QueueStack<Q> {
+        QueueStack::new()
+    }
+}
+
+impl<T, Q: IsQueue<T>> IsStack<T> for QueueStack<Q> {
+    /// Adds a new element to the top of the `QueueStack`, rotating every
+    /// older element behind it.
+    ///
+    /// # Errors
+    /// Returns `Error::Full` if the backing queue is full.
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        let older = self.queue.size();
+        self.queue.add(value)?;
+        for _ in 0..older {
+            let value = self.queue.remove()?;
+            self.queue.add(value)?;
+        }
+        Ok(None)
+    }
+
+    /// Removes the newest value from the `QueueStack` and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `QueueStack` is empty.
+    fn remove(&mut self) -> Result<T, Error> {
+        self.queue.remove()
+    }
+
+    /// Gets a reference to the newest value in the `QueueStack`.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `QueueStack` is empty.
+    fn peek(&self) -> Result<&T, Error> {
+        self.queue.peek()
+    }
+
+    /// Gets the size of the `QueueStack`.
+    fn size(&self) -> usize {
+        self.queue.size()
+    }
+
+    /// Gets the number of elements that can be added before the backing
+    /// queue is full.
+    fn remaining_capacity(&self) -> Option<usize> {
+        self.queue.remaining_capacity()
+    }
+}
+
 /// Creates a new `Stack<T>`
 ///
 /// Delegates to the default stack initializer. Note that the values are
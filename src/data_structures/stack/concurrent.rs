@@ -0,0 +1,164 @@
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crossbeam_epoch::{self as epoch, Atomic, Owned};
+
+use crate::error::Error;
+
+/// Defines methods expected on a stack shared between threads.
+///
+/// The methods mirror `IsStack`, but take `&self` so a stack can be wrapped
+/// in an `Arc` and shared by any number of threads. There is no `peek`: once
+/// a reference to the top element was handed out, another thread could
+/// remove and drop that element while the reference is still in use.
+pub trait IsConcurrentStack<T> {
+    /// Adds a new value to the top of the stack.
+    ///
+    /// # Returns
+    /// - `Ok(None)`: If the element was added
+    /// - `Err(Error::Full)`: If the stack is bounded and at capacity
+    fn add(&self, value: T) -> Result<Option<T>, Error>;
+
+    /// Removes the value on top of the stack and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the stack was empty when checked.
+    fn remove(&self) -> Result<T, Error>;
+
+    /// Gets the number of elements in the stack.
+    ///
+    /// Other threads may add or remove at any time, so the result is only a
+    /// snapshot.
+    fn size(&self) -> usize;
+
+    /// Returns `true` if the stack contained no elements when checked.
+    fn is_empty(&self) -> bool {
+        self.size() == 0
+    }
+}
+
+struct Node<T> {
+    value: ManuallyDrop<T>,
+    next: Atomic<Node<T>>,
+}
+
+/// A lock-free stack (LIFO), as described by R. K. Treiber.
+///
+/// The stack is a singly linked list whose head is swapped in with a single
+/// compare-and-swap, so no thread ever blocks another. A thread that removes
+/// a node cannot free it straight away, since other threads may still be
+/// reading it; nodes are instead retired through epoch-based reclamation and
+/// freed once every thread that could have seen them has moved on.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use std::thread;
+/// # use crate::algorithms::data_structures::stack::{IsConcurrentStack, TreiberStack};
+/// let stack = Arc::new(TreiberStack::new());
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|t| {
+///         let stack = Arc::clone(&stack);
+///         thread::spawn(move || {
+///             for i in 0..100 {
+///                 stack.add(t * 100 + i).unwrap();
+///             }
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// assert_eq!(stack.size(), 400);
+/// ```
+pub struct TreiberStack<T> {
+    head: Atomic<Node<T>>,
+    len: AtomicUsize,
+}
+
+impl<T> TreiberStack<T> {
+    /// Creates a new empty `TreiberStack`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::data_structures::stack::{IsConcurrentStack, TreiberStack};
+    /// let stack: TreiberStack<isize> = TreiberStack::new();
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn new() -> TreiberStack<T> {
+        TreiberStack { head: Atomic::null(), len: AtomicUsize::new(0) }
+    }
+}
+
+impl<T> Default for TreiberStack<T> {
+    /// Initializes a `Default` `TreiberStack`.
+    fn default() -> TreiberStack<T> {
+        TreiberStack::new()
+    }
+}
+
+impl<T> IsConcurrentStack<T> for TreiberStack<T> {
+    /// Adds a new value to the top of the `TreiberStack`.
+    ///
+    /// # Returns
+    /// `Ok(None)` as the element addition should always be successful.
+    fn add(&self, value: T) -> Result<Option<T>, Error> {
+        // Count the element before publishing it, so a concurrent `remove`
+        // can never bring the count below zero.
+        self.len.fetch_add(1, Ordering::Relaxed);
+
+        let mut node = Owned::new(Node { value: ManuallyDrop::new(value), next: Atomic::null() });
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Ordering::Relaxed, &guard);
+            node.next.store(head, Ordering::Relaxed);
+            match self.head.compare_exchange(head, node, Ordering::Release, Ordering::Relaxed, &guard) {
+                Ok(_) => return Ok(None),
+                Err(e) => node = e.new,
+            }
+        }
+    }
+
+    /// Removes the value on top of the `TreiberStack` and returns it.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the `TreiberStack` was empty when checked.
+    fn remove(&self) -> Result<T, Error> {
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Ordering::Acquire, &guard);
+            // SAFETY: the guard keeps any node reachable from `head` alive.
+            let node = unsafe { head.as_ref() }.ok_or(Error::Empty)?;
+            let next = node.next.load(Ordering::Relaxed, &guard);
+
+            if self.head
+                .compare_exchange(head, next, Ordering::Relaxed, Ordering::Relaxed, &guard)
+                .is_ok()
+            {
+                self.len.fetch_sub(1, Ordering::Relaxed);
+                // SAFETY: the successful swap unlinked `node`, so this thread
+                // is the only one that will move its value out, and the node
+                // itself is freed only after every pinned thread has moved on.
+                unsafe {
+                    guard.defer_destroy(head);
+                    return Ok(ManuallyDrop::into_inner(ptr::read(&node.value)));
+                }
+            }
+        }
+    }
+
+    /// Gets the number of elements in the `TreiberStack`.
+    fn size(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+}
+
+impl<T> Drop for TreiberStack<T> {
+    fn drop(&mut self) {
+        while self.remove().is_ok() {}
+    }
+}
@@ -0,0 +1,119 @@
+use std::fmt;
+
+/// Errors returned by the data structures in this crate.
+///
+/// # Examples
+///
+/// ```
+/// # use crate::algorithms::data_structures::stack::*;
+/// # use crate::algorithms::error::Error;
+/// let mut stack: Stack<isize> = Stack::new();
+///
+/// assert_eq!(stack.remove(), Err(Error::Empty));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The container has no elements to remove or read.
+    Empty,
+
+    /// The container is at capacity and does not allow overflow.
+    Full,
+
+    /// An `index` was outside a dimension of length `len`.
+    OutOfBounds { index: usize, len: usize },
+
+    /// Operand dimensions, as (`rows`, `cols`), do not agree.
+    DimensionMismatch { expected: (usize, usize), found: (usize, usize) },
+
+    /// A buffer held `found` values where `expected` were needed.
+    LengthMismatch { expected: usize, found: usize },
+
+    /// A matrix was requested with zero `rows` or zero `cols`.
+    ZeroDimension { rows: usize, cols: usize },
+
+    /// A heap `parent` and `child` are out of order.
+    HeapViolation { parent: usize, child: usize },
+
+    /// A `priority` is outside the range (`min`..=`max`) a monotone
+    /// priority queue can currently accept.
+    PriorityOutOfRange { priority: usize, min: usize, max: usize },
+
+    /// A new `priority` is not lower than the `current` one.
+    PriorityNotDecreased { current: usize, priority: usize },
+
+    /// The value is already present in the container.
+    Duplicate,
+
+    /// The value is not present in the container.
+    NotFound,
+
+    /// The other side of a channel has been closed or dropped.
+    Disconnected,
+
+    /// A blocking operation did not complete before its timeout elapsed.
+    Timeout,
+
+    /// The matrix is singular (or numerically close to it), so it has no
+    /// inverse and linear systems involving it have no unique solution.
+    Singular,
+
+    /// The matrix is not symmetric: the entries at (`row`, `col`) and
+    /// (`col`, `row`) differ.
+    NotSymmetric { row: usize, col: usize },
+
+    /// The symmetric matrix is not positive definite, which was detected at
+    /// the pivot in row `index`, or in iteration `index` of an iterative
+    /// solver.
+    NotPositiveDefinite { index: usize },
+
+    /// An iterative method did not converge within `iterations` iterations.
+    NotConverged { iterations: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Empty => write!(f, "the container is empty"),
+            Error::Full => write!(f, "the container is full"),
+            Error::OutOfBounds { index, len } => {
+                write!(f, "index {} is out of bounds for length {}", index, len)
+            }
+            Error::DimensionMismatch { expected, found } => write!(
+                f, "expected dimensions {}x{} but found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            Error::LengthMismatch { expected, found } => {
+                write!(f, "expected {} values but found {}", expected, found)
+            }
+            Error::ZeroDimension { rows, cols } => {
+                write!(f, "a {}x{} matrix has no values", rows, cols)
+            }
+            Error::HeapViolation { parent, child } => {
+                write!(f, "heap property violated between parent {} and child {}", parent, child)
+            }
+            Error::PriorityOutOfRange { priority, min, max } => {
+                write!(f, "priority {} is outside the accepted range {}..={}", priority, min, max)
+            }
+            Error::PriorityNotDecreased { current, priority } => {
+                write!(f, "priority {} is not lower than the current priority {}", priority, current)
+            }
+            Error::Duplicate => write!(f, "the value is already present"),
+            Error::NotFound => write!(f, "the value is not present"),
+            Error::Disconnected => write!(f, "the channel is disconnected"),
+            Error::Timeout => write!(f, "the operation timed out"),
+            Error::Singular => write!(f, "the matrix is singular"),
+            Error::NotSymmetric { row, col } => {
+                write!(f, "the matrix is not symmetric at ({}, {})", row, col)
+            }
+            Error::NotPositiveDefinite { index } => {
+                write!(f, "the matrix is not positive definite at index {}", index)
+            }
+            Error::NotConverged { iterations } => {
+                write!(f, "failed to converge after {} iterations", iterations)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -3,4 +3,6 @@
 //! **Algorithms** is a collection of common algorithms implemented in rust.
 
 pub mod sort;
-pub mod data_structures;
\ No newline at end of file
+pub mod data_structures;
+pub mod error;
+pub mod sync;
\ No newline at end of file
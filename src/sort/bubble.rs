@@ -31,9 +31,7 @@ where
 {
     for i in 0..data.len() {
         for j in ((i + 1)..data.len()).rev() {
-            if asc && data[j] < data[j - 1] {
-                data.swap(j, j - 1);
-            } else if !asc && data[j] > data[j - 1] {
+            if (asc && data[j] < data[j - 1]) || (!asc && data[j] > data[j - 1]) {
                 data.swap(j, j - 1);
             }
         }
@@ -35,12 +35,12 @@ where
         if asc {
             while j > 0 && data[j - 1] > data[j] {
                 data.swap(j - 1, j);
-                j = j - 1;
+                j -= 1;
             }
         } else {
             while j > 0 && data[j - 1] < data[j] {
                 data.swap(j - 1, j);
-                j = j - 1;
+                j -= 1;
             }
         }
     }
@@ -52,28 +52,25 @@ where
     let mut k = p;
 
     while i < left.len() && j < right.len() {
-        if asc && left[i] <= right[j] {
+        if (asc && left[i] <= right[j]) || (!asc && left[i] >= right[j]) {
             data[k] = left[i];
-            i = i + 1;
-        } else if !asc && left[i] >= right[j] {
-            data[k] = left[i];
-            i = i + 1;
+            i += 1;
         } else {
             data[k] = right[j];
-            j = j + 1;
+            j += 1;
         }
-        k = k + 1;
+        k += 1;
     }
 
     while i < left.len() {
         data[k] = left[i];
-        i = i + 1;
-        k = k + 1;
+        i += 1;
+        k += 1;
     }
 
     while j < right.len() {
         data[k] = right[j];
-        j = j + 1;
-        k = k + 1;
+        j += 1;
+        k += 1;
     }
 }
\ No newline at end of file
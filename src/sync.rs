@@ -0,0 +1 @@
+pub mod channel;
\ No newline at end of file
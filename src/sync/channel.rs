@@ -0,0 +1,479 @@
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::data_structures::queue::{BoundedQueue, IsQueue};
+use crate::error::Error;
+
+/// Creates a bounded multi-producer, multi-consumer channel holding at most
+/// `capacity` values.
+///
+/// Values are buffered in a `BoundedQueue` behind a `Mutex`. `send` blocks
+/// while the queue is full and `recv` blocks while it is empty. Both halves
+/// can be cloned to add more producers or consumers.
+///
+/// The channel is disconnected once either half is closed, or once every
+/// `Sender` or every `Receiver` has been dropped. After that, sending fails,
+/// while receivers can still drain any values already buffered.
+///
+/// # Panics
+/// Panics if `capacity` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use std::thread;
+/// # use crate::algorithms::sync::channel;
+/// let (tx, rx) = channel::channel(2);
+///
+/// let producer = thread::spawn(move || {
+///     for i in 0..5 {
+///         tx.send(i).unwrap();
+///     }
+///     // Dropping the only sender disconnects the channel
+/// });
+///
+/// // Iteration ends once the channel is disconnected and drained
+/// let received: Vec<i32> = rx.iter().collect();
+/// assert_eq!(received, [0, 1, 2, 3, 4]);
+/// producer.join().unwrap();
+/// ```
+pub fn channel<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: BoundedQueue::new(capacity),
+            senders: 1,
+            receivers: 1,
+            closed: false,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+    });
+
+    (Sender { shared: Arc::clone(&shared) }, Receiver { shared })
+}
+
+/// An error returned when a value could not be sent, handing the value back
+/// to the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T> {
+    /// The value that was not sent.
+    pub value: T,
+
+    /// Why the value was not sent: `Error::Full`, `Error::Timeout` or
+    /// `Error::Disconnected`.
+    pub error: Error,
+}
+
+impl<T> SendError<T> {
+    /// Consumes the error, returning the value that was not sent.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to send value: {}", self.error)
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for SendError<T> {}
+
+struct State<T> {
+    queue: BoundedQueue<T>,
+    senders: usize,
+    receivers: usize,
+    closed: bool,
+}
+
+impl<T> State<T> {
+    fn send_disconnected(&self) -> bool {
+        self.closed || self.receivers == 0
+    }
+
+    fn recv_disconnected(&self) -> bool {
+        self.closed || self.senders == 0
+    }
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Marks the channel closed and wakes every blocked thread so they can
+    /// observe it.
+    fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+
+    fn len(&self) -> usize {
+        self.lock().queue.size()
+    }
+
+    /// Sends `value`, waiting for room until `deadline` (if any). A deadline
+    /// in the past makes this a non-blocking attempt that fails with
+    /// `Error::Full`.
+    fn send(&self, value: T, deadline: Option<Instant>, timeout: Error) -> Result<(), SendError<T>> {
+        let mut state = self.lock();
+        loop {
+            if state.send_disconnected() {
+                return Err(SendError { value, error: Error::Disconnected });
+            }
+            if !state.queue.is_full() {
+                break;
+            }
+
+            state = match deadline {
+                None => self.not_full.wait(state).unwrap_or_else(|e| e.into_inner()),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(SendError { value, error: timeout });
+                    }
+                    self.not_full
+                        .wait_timeout(state, deadline - now)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+            };
+        }
+
+        // The queue has room, so `add` cannot fail.
+        let _ = state.queue.add(value);
+        drop(state);
+        self.not_empty.notify_one();
+        Ok(())
+    }
+
+    /// Receives a value, waiting until `deadline` (if any). A deadline in the
+    /// past makes this a non-blocking attempt that fails with `Error::Empty`.
+    fn recv(&self, deadline: Option<Instant>, timeout: Error) -> Result<T, Error> {
+        let mut state = self.lock();
+        loop {
+            if let Ok(value) = state.queue.remove() {
+                drop(state);
+                self.not_full.notify_one();
+                return Ok(value);
+            }
+            if state.recv_disconnected() {
+                return Err(Error::Disconnected);
+            }
+
+            state = match deadline {
+                None => self.not_empty.wait(state).unwrap_or_else(|e| e.into_inner()),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Err(timeout);
+                    }
+                    self.not_empty
+                        .wait_timeout(state, deadline - now)
+                        .unwrap_or_else(|e| e.into_inner())
+                        .0
+                }
+            };
+        }
+    }
+}
+
+/// The sending half of a channel created by `channel`.
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    /// Sends a value, blocking the current thread while the channel is full.
+    ///
+    /// # Errors
+    /// Returns the value with `Error::Disconnected` if the channel is
+    /// closed or every `Receiver` has been dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::sync::channel;
+    /// # use crate::algorithms::error::Error;
+    /// let (tx, rx) = channel::channel(1);
+    /// tx.send(1).unwrap();
+    ///
+    /// drop(rx);
+    /// assert_eq!(tx.send(2).unwrap_err().error, Error::Disconnected);
+    /// ```
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        self.shared.send(value, None, Error::Full)
+    }
+
+    /// Sends a value without blocking.
+    ///
+    /// # Errors
+    /// Returns the value with `Error::Full` if the channel is full, or with
+    /// `Error::Disconnected` if the channel is disconnected.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::sync::channel;
+    /// # use crate::algorithms::error::Error;
+    /// let (tx, _rx) = channel::channel(1);
+    /// tx.try_send(1).unwrap();
+    ///
+    /// let err = tx.try_send(2).unwrap_err();
+    /// assert_eq!(err.error, Error::Full);
+    /// assert_eq!(err.into_inner(), 2);
+    /// ```
+    pub fn try_send(&self, value: T) -> Result<(), SendError<T>> {
+        self.shared.send(value, Some(Instant::now()), Error::Full)
+    }
+
+    /// Sends a value, blocking the current thread for at most `timeout`
+    /// while the channel is full.
+    ///
+    /// # Errors
+    /// Returns the value with `Error::Timeout` if the channel stayed full,
+    /// or with `Error::Disconnected` if the channel is disconnected.
+    pub fn send_timeout(&self, value: T, timeout: Duration) -> Result<(), SendError<T>> {
+        self.shared.send(value, Some(Instant::now() + timeout), Error::Timeout)
+    }
+
+    /// Closes the channel for every sender and receiver.
+    ///
+    /// Blocked threads are woken. Further sends fail, while receivers can
+    /// still drain the values already buffered.
+    pub fn close(&self) {
+        self.shared.close();
+    }
+
+    /// Gets the number of values buffered in the channel.
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Returns `true` if no values were buffered in the channel when checked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    /// Creates another `Sender` for the same channel.
+    fn clone(&self) -> Sender<T> {
+        self.shared.lock().senders += 1;
+        Sender { shared: Arc::clone(&self.shared) }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    /// Disconnects the channel if this was the last `Sender`, waking any
+    /// blocked receivers.
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+/// The receiving half of a channel created by `channel`.
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Receiver<T> {
+    /// Receives the oldest value, blocking the current thread while the
+    /// channel is empty.
+    ///
+    /// # Errors
+    /// Returns `Error::Disconnected` if the channel is empty and
+    /// disconnected.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::sync::channel;
+    /// # use crate::algorithms::error::Error;
+    /// let (tx, rx) = channel::channel(2);
+    /// tx.send(1).unwrap();
+    /// drop(tx);
+    ///
+    /// // Buffered values are still delivered after disconnection
+    /// assert_eq!(rx.recv().unwrap(), 1);
+    /// assert_eq!(rx.recv(), Err(Error::Disconnected));
+    /// ```
+    pub fn recv(&self) -> Result<T, Error> {
+        self.shared.recv(None, Error::Empty)
+    }
+
+    /// Receives the oldest value without blocking.
+    ///
+    /// # Errors
+    /// Returns `Error::Empty` if the channel is empty, or
+    /// `Error::Disconnected` if it is also disconnected.
+    pub fn try_recv(&self) -> Result<T, Error> {
+        self.shared.recv(Some(Instant::now()), Error::Empty)
+    }
+
+    /// Receives the oldest value, blocking the current thread for at most
+    /// `timeout` while the channel is empty.
+    ///
+    /// # Errors
+    /// Returns `Error::Timeout` if no value arrived in time, or
+    /// `Error::Disconnected` if the channel is empty and disconnected.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::time::Duration;
+    /// # use crate::algorithms::sync::channel;
+    /// # use crate::algorithms::error::Error;
+    /// let (_tx, rx) = channel::channel::<i32>(1);
+    /// assert_eq!(rx.recv_timeout(Duration::from_millis(10)), Err(Error::Timeout));
+    /// ```
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, Error> {
+        self.shared.recv(Some(Instant::now() + timeout), Error::Timeout)
+    }
+
+    /// Iterates over received values, blocking for each one. The iterator
+    /// ends once the channel is disconnected and drained.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { receiver: self }
+    }
+
+    /// Iterates over the values currently buffered, without blocking.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crate::algorithms::sync::channel;
+    /// let (tx, rx) = channel::channel(4);
+    /// tx.send(1).unwrap();
+    /// tx.send(2).unwrap();
+    ///
+    /// assert_eq!(rx.try_iter().collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(rx.try_iter().count(), 0);
+    /// ```
+    pub fn try_iter(&self) -> TryIter<'_, T> {
+        TryIter { receiver: self }
+    }
+
+    /// Closes the channel for every sender and receiver.
+    ///
+    /// Blocked threads are woken. Further sends fail, while receivers can
+    /// still drain the values already buffered.
+    pub fn close(&self) {
+        self.shared.close();
+    }
+
+    /// Gets the number of values buffered in the channel.
+    pub fn len(&self) -> usize {
+        self.shared.len()
+    }
+
+    /// Returns `true` if no values were buffered in the channel when checked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    /// Creates another `Receiver` for the same channel. Each value is
+    /// delivered to exactly one receiver.
+    fn clone(&self) -> Receiver<T> {
+        self.shared.lock().receivers += 1;
+        Receiver { shared: Arc::clone(&self.shared) }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    /// Disconnects the channel if this was the last `Receiver`, waking any
+    /// blocked senders.
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receivers -= 1;
+        if state.receivers == 0 {
+            drop(state);
+            self.shared.not_full.notify_all();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
+
+/// A blocking iterator over the values received on a channel, created by
+/// `Receiver::iter`.
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    receiver: &'a Receiver<T>,
+}
+
+impl<T> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv().ok()
+    }
+}
+
+/// A non-blocking iterator over the values buffered in a channel, created
+/// by `Receiver::try_iter`.
+#[derive(Debug)]
+pub struct TryIter<'a, T> {
+    receiver: &'a Receiver<T>,
+}
+
+impl<T> Iterator for TryIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+/// A blocking iterator that owns its `Receiver`, created by
+/// `Receiver::into_iter`.
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    receiver: Receiver<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.receiver.recv().ok()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Receiver<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for Receiver<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { receiver: self }
+    }
+}
@@ -0,0 +1,409 @@
+use algorithms::data_structures::heap::*;
+use algorithms::data_structures::queue::{IsConcurrentQueue, MichaelScottQueue};
+use algorithms::data_structures::stack::{IsConcurrentStack, TreiberStack};
+use algorithms::error::Error;
+use algorithms::sync::channel;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const THREADS: usize = 4;
+const PER_THREAD: usize = 1000;
+
+// Pushes `PER_THREAD` values from each of `THREADS` producers while the same
+// number of consumers pop with `pop_wait`, then checks every value arrived once.
+fn run_producers_consumers<Q>(queue: Arc<Q>)
+where
+    Q: IsConcurrentPriorityQueue<usize> + Send + Sync + 'static
+{
+    let producers: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || {
+                for i in 0..PER_THREAD {
+                    queue.push(t * PER_THREAD + i);
+                }
+            })
+        })
+        .collect();
+
+    let consumers: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let queue = Arc::clone(&queue);
+            thread::spawn(move || (0..PER_THREAD).map(|_| queue.pop_wait()).collect::<Vec<_>>())
+        })
+        .collect();
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+
+    let mut received: Vec<usize> = consumers
+        .into_iter()
+        .flat_map(|consumer| consumer.join().unwrap())
+        .collect();
+    received.sort();
+
+    assert_eq!(received, (0..THREADS * PER_THREAD).collect::<Vec<_>>());
+    assert!(queue.is_empty());
+}
+
+
+// LockedPriorityQueue Tests
+#[test]
+fn test_locked_priority_queue_order() {
+    let queue = LockedPriorityQueue::new_min();
+    for value in [5, 3, 8, 1, 9] {
+        queue.push(value);
+    }
+
+    let popped: Vec<i32> = (0..5).map(|_| queue.try_pop().unwrap()).collect();
+    assert_eq!(popped, vec![1, 3, 5, 8, 9]);
+    assert_eq!(queue.try_pop(), None);
+}
+
+#[test]
+fn test_locked_priority_queue_threads() {
+    run_producers_consumers(Arc::new(LockedPriorityQueue::new_max()));
+}
+
+#[test]
+fn test_locked_priority_queue_pop_wait_wakes() {
+    let queue = Arc::new(LockedPriorityQueue::new_max());
+    let consumer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || queue.pop_wait())
+    };
+
+    thread::sleep(Duration::from_millis(20));
+    queue.push(42);
+    assert_eq!(consumer.join().unwrap(), 42);
+}
+
+#[test]
+fn test_locked_priority_queue_pop_timeout() {
+    let queue: LockedPriorityQueue<i32> = LockedPriorityQueue::new_min();
+    let start = Instant::now();
+    assert_eq!(queue.pop_timeout(Duration::from_millis(20)), None);
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    queue.push(1);
+    assert_eq!(queue.pop_timeout(Duration::from_millis(20)), Some(1));
+}
+
+
+// MultiQueue Tests
+#[test]
+fn test_multi_queue_single_shard_is_exact() {
+    let queue = MultiQueue::new_max(1);
+    for value in [5, 3, 8, 1, 9] {
+        queue.push(value);
+    }
+
+    let popped: Vec<i32> = (0..5).map(|_| queue.try_pop().unwrap()).collect();
+    assert_eq!(popped, vec![9, 8, 5, 3, 1]);
+    assert_eq!(queue.try_pop(), None);
+}
+
+#[test]
+fn test_multi_queue_drains_all_shards() {
+    let queue = MultiQueue::new_min(16);
+    for value in 0..100 {
+        queue.push(value);
+    }
+    assert_eq!(queue.len(), 100);
+
+    let mut popped: Vec<i32> = (0..100).map(|_| queue.try_pop().unwrap()).collect();
+    popped.sort();
+    assert_eq!(popped, (0..100).collect::<Vec<_>>());
+    assert_eq!(queue.try_pop(), None);
+}
+
+#[test]
+fn test_multi_queue_threads() {
+    run_producers_consumers(Arc::new(MultiQueue::new_min(2 * THREADS)));
+}
+
+#[test]
+fn test_multi_queue_pop_timeout() {
+    let queue: Arc<MultiQueue<i32>> = Arc::new(MultiQueue::new_min(4));
+    assert_eq!(queue.pop_timeout(Duration::from_millis(20)), None);
+
+    let producer = {
+        let queue = Arc::clone(&queue);
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            queue.push(7);
+        })
+    };
+    assert_eq!(queue.pop_timeout(Duration::from_secs(5)), Some(7));
+    producer.join().unwrap();
+}
+
+
+// Lock-free Stack and Queue Tests
+
+// Lock-free races are rare, so each threaded test repeats several times.
+const ROUNDS: usize = 20;
+
+// Runs `THREADS` producers adding distinct values alongside `THREADS`
+// consumers spinning on `remove`, checks every value arrived once, and
+// returns the values each consumer received in order.
+fn run_lock_free<S, A, R>(shared: Arc<S>, add: A, remove: R) -> Vec<Vec<usize>>
+where
+    S: Send + Sync + 'static,
+    A: Fn(&S, usize) + Copy + Send + 'static,
+    R: Fn(&S) -> Option<usize> + Copy + Send + 'static,
+{
+    let received = Arc::new(AtomicUsize::new(0));
+    let producers: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                for i in 0..PER_THREAD {
+                    add(&shared, t * PER_THREAD + i);
+                }
+            })
+        })
+        .collect();
+
+    let consumers: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let shared = Arc::clone(&shared);
+            let received = Arc::clone(&received);
+            thread::spawn(move || {
+                let mut values = vec![];
+                while received.load(Ordering::Relaxed) < THREADS * PER_THREAD {
+                    if let Some(value) = remove(&shared) {
+                        received.fetch_add(1, Ordering::Relaxed);
+                        values.push(value);
+                    }
+                }
+                values
+            })
+        })
+        .collect();
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    let per_consumer: Vec<Vec<usize>> = consumers.into_iter().map(|c| c.join().unwrap()).collect();
+
+    let mut all: Vec<usize> = per_consumer.iter().flatten().copied().collect();
+    all.sort();
+    assert_eq!(all, (0..THREADS * PER_THREAD).collect::<Vec<_>>());
+    per_consumer
+}
+
+#[test]
+fn test_treiber_stack_lifo() {
+    let stack = TreiberStack::new();
+    for value in 0..5 {
+        stack.add(value).unwrap();
+    }
+    assert_eq!(stack.size(), 5);
+
+    let popped: Vec<i32> = (0..5).map(|_| stack.remove().unwrap()).collect();
+    assert_eq!(popped, vec![4, 3, 2, 1, 0]);
+    assert_eq!(stack.remove(), Err(Error::Empty));
+    assert!(stack.is_empty());
+}
+
+#[test]
+fn test_treiber_stack_threads() {
+    for _ in 0..ROUNDS {
+        let stack = Arc::new(TreiberStack::new());
+        run_lock_free(
+            Arc::clone(&stack),
+            |s: &TreiberStack<usize>, v| { s.add(v).unwrap(); },
+            |s| s.remove().ok(),
+        );
+        assert!(stack.is_empty());
+    }
+}
+
+#[test]
+fn test_michael_scott_queue_fifo() {
+    let queue = MichaelScottQueue::new();
+    for value in 0..5 {
+        queue.add(value).unwrap();
+    }
+    assert_eq!(queue.size(), 5);
+
+    let popped: Vec<i32> = (0..5).map(|_| queue.remove().unwrap()).collect();
+    assert_eq!(popped, vec![0, 1, 2, 3, 4]);
+    assert_eq!(queue.remove(), Err(Error::Empty));
+
+    // The queue keeps working after being drained to its sentinel
+    queue.add(5).unwrap();
+    assert_eq!(queue.remove().unwrap(), 5);
+}
+
+#[test]
+fn test_michael_scott_queue_threads() {
+    for _ in 0..ROUNDS {
+        let queue = Arc::new(MichaelScottQueue::new());
+        let per_consumer = run_lock_free(
+            Arc::clone(&queue),
+            |q: &MichaelScottQueue<usize>, v| { q.add(v).unwrap(); },
+            |q| q.remove().ok(),
+        );
+        assert!(queue.is_empty());
+
+        // Each consumer sees the values of any one producer in FIFO order
+        for values in per_consumer {
+            for t in 0..THREADS {
+                let from_t: Vec<usize> = values.iter().copied().filter(|v| v / PER_THREAD == t).collect();
+                assert!(from_t.windows(2).all(|w| w[0] < w[1]));
+            }
+        }
+    }
+}
+
+// Counts live instances so tests can check that every value is dropped
+// exactly once, whether removed or left behind when the container is dropped.
+struct Tracked(Arc<AtomicUsize>);
+
+impl Tracked {
+    fn new(live: &Arc<AtomicUsize>) -> Tracked {
+        live.fetch_add(1, Ordering::Relaxed);
+        Tracked(Arc::clone(live))
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_lock_free_drops_every_value() {
+    let live = Arc::new(AtomicUsize::new(0));
+    {
+        let stack = TreiberStack::new();
+        let queue = MichaelScottQueue::new();
+        for _ in 0..100 {
+            stack.add(Tracked::new(&live)).unwrap();
+            queue.add(Tracked::new(&live)).unwrap();
+        }
+        for _ in 0..50 {
+            drop(stack.remove().unwrap());
+            drop(queue.remove().unwrap());
+        }
+        assert_eq!(live.load(Ordering::Relaxed), 100);
+    }
+    assert_eq!(live.load(Ordering::Relaxed), 0);
+}
+
+
+// Channel Tests
+#[test]
+fn test_channel_fifo_and_try_send() {
+    let (tx, rx) = channel::channel(2);
+    tx.try_send(1).unwrap();
+    tx.try_send(2).unwrap();
+
+    let err = tx.try_send(3).unwrap_err();
+    assert_eq!(err.error, Error::Full);
+    assert_eq!(err.into_inner(), 3);
+    assert_eq!(rx.len(), 2);
+
+    assert_eq!(rx.recv().unwrap(), 1);
+    assert_eq!(rx.try_recv().unwrap(), 2);
+    assert_eq!(rx.try_recv(), Err(Error::Empty));
+}
+
+#[test]
+fn test_channel_send_blocks_until_room() {
+    let (tx, rx) = channel::channel(1);
+    tx.send(1).unwrap();
+
+    let producer = thread::spawn(move || {
+        tx.send(2).unwrap();
+        assert_eq!(
+            tx.send_timeout(3, Duration::from_millis(20)).unwrap_err().error,
+            Error::Timeout
+        );
+    });
+
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(rx.recv().unwrap(), 1);
+    producer.join().unwrap();
+    assert_eq!(rx.recv().unwrap(), 2);
+    assert_eq!(rx.recv(), Err(Error::Disconnected));
+}
+
+#[test]
+fn test_channel_recv_timeout() {
+    let (tx, rx) = channel::channel(1);
+    let start = Instant::now();
+    assert_eq!(rx.recv_timeout(Duration::from_millis(20)), Err(Error::Timeout));
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    let producer = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        tx.send(7).unwrap();
+    });
+    assert_eq!(rx.recv_timeout(Duration::from_secs(5)), Ok(7));
+    producer.join().unwrap();
+}
+
+#[test]
+fn test_channel_close_wakes_and_drains() {
+    let (tx, rx) = channel::channel::<i32>(4);
+    let blocked = {
+        let rx = rx.clone();
+        thread::spawn(move || rx.recv())
+    };
+
+    // Closing wakes the blocked receiver even though a sender is still alive
+    thread::sleep(Duration::from_millis(20));
+    tx.close();
+    assert_eq!(blocked.join().unwrap(), Err(Error::Disconnected));
+    assert_eq!(tx.send(1).unwrap_err().error, Error::Disconnected);
+
+    // Values buffered before closing can still be received
+    let (tx, rx) = channel::channel(4);
+    tx.send(1).unwrap();
+    tx.send(2).unwrap();
+    rx.close();
+    assert_eq!(rx.iter().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(rx.try_recv(), Err(Error::Disconnected));
+}
+
+#[test]
+fn test_channel_threads() {
+    let (tx, rx) = channel::channel(16);
+    let producers: Vec<_> = (0..THREADS)
+        .map(|t| {
+            let tx = tx.clone();
+            thread::spawn(move || {
+                for i in 0..PER_THREAD {
+                    tx.send(t * PER_THREAD + i).unwrap();
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    let consumers: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let rx = rx.clone();
+            thread::spawn(move || rx.into_iter().collect::<Vec<_>>())
+        })
+        .collect();
+    drop(rx);
+
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    let mut received: Vec<usize> = consumers
+        .into_iter()
+        .flat_map(|consumer| consumer.join().unwrap())
+        .collect();
+    received.sort();
+
+    assert_eq!(received, (0..THREADS * PER_THREAD).collect::<Vec<_>>());
+}
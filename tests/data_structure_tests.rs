@@ -1,5 +1,13 @@
-use algorithms::data_structures::{stack::*, queue::*, matrix::*};
+use algorithms::data_structures::{stack::*, queue::*, matrix::*, heap::*, circular_buffer::*, deque::*, sparse::*};
+use algorithms::error::Error;
 use algorithms::matrix;
+use rand::{thread_rng, Rng};
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn get_random_vec_int(len: usize) -> Vec<i32> {
+    (0..len).map(|_| thread_rng().gen_range(-100..100)).collect()
+}
 
 
 
@@ -20,7 +28,7 @@ fn test_stack_add() {
 #[test]
 fn test_stack_remove() {
     let mut stack: Stack<isize> = Stack::new();
-    stack.add(42);
+    stack.add(42).unwrap();
     assert_eq!(stack.remove().unwrap(), 42);
     assert_eq!(stack.size(), 0);   
 }
@@ -28,15 +36,78 @@ fn test_stack_remove() {
 #[test]
 fn test_stack_read() {
     let mut stack: Stack<isize> = Stack::new();
-    stack.add(42);
+    stack.add(42).unwrap();
     assert_eq!(stack.read().unwrap(), 42);
 }
 
 #[test]
-#[should_panic]
 fn test_read_empty() {
     let mut stack: Stack<isize> = Stack::new();
-    stack.read();
+    assert_eq!(stack.read(), Err(Error::Empty));
+    assert_eq!(stack.remove(), Err(Error::Empty));
+}
+
+#[test]
+fn test_bounded_stack() {
+    let mut stack: BoundedStack<i32> = BoundedStack::new(3);
+    for i in 0..3 {
+        assert_eq!(stack.remaining(), 3 - i as usize);
+        stack.add(i).unwrap();
+    }
+    assert!(stack.is_full());
+    assert_eq!(stack.add(3), Err(Error::Full));
+    assert_eq!(stack.size(), 3);
+
+    assert_eq!(stack.remove().unwrap(), 2);
+    assert_eq!(stack.add(4).unwrap(), None);
+    assert_eq!(stack.read().unwrap(), 4);
+    assert_eq!(stack.capacity(), 3);
+}
+
+
+#[test]
+fn test_stack_iterators() {
+    let mut stack: Stack<i32> = (1..=3).collect();
+    stack.extend([4, 5]);
+
+    assert_eq!(stack.iter().copied().collect::<Vec<_>>(), [5, 4, 3, 2, 1]);
+    assert_eq!((&stack).into_iter().count(), 5);
+
+    let copy = stack.clone();
+    assert_eq!(copy, stack);
+    assert_eq!(stack.into_iter().collect::<Vec<_>>(), [5, 4, 3, 2, 1]);
+}
+
+#[test]
+fn test_stack_peek_without_clone() {
+    #[derive(Debug, PartialEq)]
+    struct Token(u8);
+
+    let mut stack = Stack::new();
+    stack.add(Token(1)).unwrap();
+    stack.add(Token(2)).unwrap();
+    assert_eq!(stack.peek().unwrap(), &Token(2));
+
+    stack.clear();
+    assert!(stack.is_empty());
+    assert_eq!(stack.peek(), Err(Error::Empty));
+}
+
+#[test]
+fn test_min_max_stack_matches_brute_force() {
+    let mut stack = MinMaxStack::new();
+    let mut reference: Vec<i32> = vec![];
+
+    for value in get_random_vec_int(1000) {
+        if value % 3 == 0 {
+            assert_eq!(stack.remove().ok(), reference.pop());
+        } else {
+            stack.add(value).unwrap();
+            reference.push(value);
+        }
+        assert_eq!(stack.min().ok(), reference.iter().min());
+        assert_eq!(stack.max().ok(), reference.iter().max());
+    }
 }
 
 
@@ -57,7 +128,7 @@ fn test_queue_add() {
 #[test]
 fn test_queue_remove() {
     let mut q: Queue<isize> = Queue::new();
-    q.add(42);
+    q.add(42).unwrap();
     assert_eq!(q.remove().unwrap(), 42);
     assert_eq!(q.size(), 0);   
 }
@@ -65,15 +136,275 @@ fn test_queue_remove() {
 #[test]
 fn test_queue_read() {
     let mut queue: Queue<isize> = Queue::new();
-    queue.add(42);
+    queue.add(42).unwrap();
     assert_eq!(queue.read().unwrap(), 42);
 }
 
 #[test]
-#[should_panic]
 fn test_queue_read_empty() {
     let mut queue: Queue<isize> = Queue::new();
-    queue.read();
+    assert_eq!(queue.read(), Err(Error::Empty));
+    assert_eq!(queue.remove(), Err(Error::Empty));
+}
+
+#[test]
+fn test_queue_fifo_order_wraps() {
+    let mut queue: Queue<usize> = Queue::with_capacity(4);
+    for i in 0..4 {
+        queue.add(i).unwrap();
+    }
+    for expected in 0..100 {
+        assert_eq!(queue.remove().unwrap(), expected);
+        queue.add(expected + 4).unwrap();
+        assert_eq!(queue.size(), 4);
+    }
+
+    let (front, back) = queue.as_slices();
+    assert_eq!([front, back].concat(), vec![100, 101, 102, 103]);
+}
+
+#[test]
+fn test_queue_drain_large() {
+    let mut queue: Queue<usize> = Queue::new();
+    for i in 0..1_000_000 {
+        queue.add(i).unwrap();
+    }
+    for i in 0..1_000_000 {
+        assert_eq!(queue.remove().unwrap(), i);
+    }
+    assert_eq!(queue.size(), 0);
+}
+
+#[test]
+fn test_queue_capacity_management() {
+    let mut queue: Queue<usize> = Queue::with_capacity(8);
+    assert!(queue.capacity() >= 8);
+
+    queue.reserve(100);
+    assert!(queue.capacity() >= 100);
+
+    queue.add(1).unwrap();
+    queue.shrink_to_fit();
+    assert!(queue.capacity() >= 1 && queue.capacity() < 100);
+    assert_eq!(queue.read().unwrap(), 1);
+}
+
+#[test]
+fn test_bounded_queue() {
+    let mut queue: BoundedQueue<i32> = BoundedQueue::new(3);
+    for i in 0..3 {
+        queue.add(i).unwrap();
+    }
+    assert!(queue.is_full());
+    assert_eq!(queue.remaining(), 0);
+    assert_eq!(queue.add(3), Err(Error::Full));
+
+    assert_eq!(queue.remove().unwrap(), 0);
+    assert_eq!(queue.add(4).unwrap(), None);
+    assert_eq!(queue.read().unwrap(), 1);
+
+    for expected in [1, 2, 4] {
+        assert_eq!(queue.remove().unwrap(), expected);
+    }
+    assert_eq!(queue.remove(), Err(Error::Empty));
+}
+
+
+#[test]
+fn test_queue_iterators() {
+    let mut queue: Queue<i32> = (1..=3).collect();
+    queue.extend([4, 5]);
+
+    assert_eq!(queue.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    assert_eq!((&queue).into_iter().count(), 5);
+
+    let copy = queue.clone();
+    assert_eq!(copy, queue);
+    assert_eq!(queue.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_queue_peek_without_clone() {
+    #[derive(Debug, PartialEq)]
+    struct Token(u8);
+
+    let mut queue = Queue::new();
+    queue.add(Token(1)).unwrap();
+    queue.add(Token(2)).unwrap();
+    assert_eq!(queue.peek().unwrap(), &Token(1));
+
+    queue.clear();
+    assert!(queue.is_empty());
+    assert_eq!(queue.peek(), Err(Error::Empty));
+}
+
+#[test]
+fn test_monotonic_queue_sliding_window() {
+    let values = get_random_vec_int(1000);
+    let mut window = MonotonicQueue::new();
+
+    for (i, value) in values.iter().enumerate() {
+        window.add(*value).unwrap();
+        if window.size() > 10 {
+            window.remove().unwrap();
+        }
+
+        let expected = &values[i.saturating_sub(9)..=i];
+        assert_eq!(window.min().unwrap(), expected.iter().min().unwrap());
+        assert_eq!(window.max().unwrap(), expected.iter().max().unwrap());
+    }
+
+    while window.remove().is_ok() {}
+    assert_eq!(window.min(), Err(Error::Empty));
+    assert_eq!(window.max(), Err(Error::Empty));
+}
+
+/// Wraps a backing container and counts every `add` and `remove` made on it.
+struct Counting<C> {
+    inner: C,
+    ops: Rc<Cell<usize>>,
+}
+
+impl<C> Counting<C> {
+    fn new(inner: C, ops: &Rc<Cell<usize>>) -> Counting<C> {
+        Counting { inner, ops: Rc::clone(ops) }
+    }
+
+    fn count(&self) {
+        self.ops.set(self.ops.get() + 1);
+    }
+}
+
+impl<T> IsStack<T> for Counting<Stack<T>> {
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        self.count();
+        self.inner.add(value)
+    }
+
+    fn remove(&mut self) -> Result<T, Error> {
+        self.count();
+        IsStack::remove(&mut self.inner)
+    }
+
+    fn peek(&self) -> Result<&T, Error> {
+        IsStack::peek(&self.inner)
+    }
+
+    fn size(&self) -> usize {
+        IsStack::size(&self.inner)
+    }
+}
+
+impl<T> IsQueue<T> for Counting<Queue<T>> {
+    fn add(&mut self, value: T) -> Result<Option<T>, Error> {
+        self.count();
+        self.inner.add(value)
+    }
+
+    fn remove(&mut self) -> Result<T, Error> {
+        self.count();
+        IsQueue::remove(&mut self.inner)
+    }
+
+    fn peek(&self) -> Result<&T, Error> {
+        IsQueue::peek(&self.inner)
+    }
+
+    fn size(&self) -> usize {
+        IsQueue::size(&self.inner)
+    }
+}
+
+#[test]
+fn test_two_stack_queue_fifo_order() {
+    let mut queue: TwoStackQueue<Stack<i32>> = TwoStackQueue::new();
+    let mut reference: Queue<i32> = Queue::new();
+
+    for value in get_random_vec_int(1000) {
+        if value % 3 == 0 {
+            assert_eq!(queue.remove().ok(), reference.remove().ok());
+        } else {
+            queue.add(value).unwrap();
+            reference.add(value).unwrap();
+        }
+        assert_eq!(queue.peek().ok(), reference.peek().ok());
+        assert_eq!(queue.size(), reference.size());
+    }
+}
+
+#[test]
+fn test_two_stack_queue_amortised_ops() {
+    const N: usize = 10_000;
+    let ops = Rc::new(Cell::new(0));
+    let mut queue = TwoStackQueue::from_stacks(
+        Counting::new(Stack::new(), &ops),
+        Counting::new(Stack::new(), &ops),
+    );
+
+    // Interleave bursts of adds and removes so the outbox is refilled many
+    // times with inboxes of varying size.
+    let mut added = 0;
+    let mut removed = 0;
+    for burst in 1..200 {
+        for _ in 0..burst % 97 {
+            if added < N {
+                queue.add(added).unwrap();
+                added += 1;
+            }
+        }
+        for _ in 0..burst % 89 {
+            if let Ok(value) = queue.remove() {
+                assert_eq!(value, removed);
+                removed += 1;
+            }
+        }
+    }
+    while let Ok(value) = queue.remove() {
+        assert_eq!(value, removed);
+        removed += 1;
+    }
+
+    // Each element is added and removed at most twice, plus one failed
+    // remove per empty attempt.
+    assert_eq!(removed, added);
+    assert!(ops.get() <= 4 * added + 200 * 89);
+}
+
+#[test]
+fn test_two_stack_queue_small_bounded_outbox() {
+    let mut queue = TwoStackQueue::from_stacks(BoundedStack::new(3), BoundedStack::new(1));
+    assert_eq!(queue.remaining_capacity(), Some(2));
+    queue.add(1).unwrap();
+    queue.add(2).unwrap();
+
+    // The outbox could not take a second inbox element once it runs dry.
+    assert_eq!(queue.add(3), Err(Error::Full));
+    assert_eq!(queue.size(), 2);
+
+    assert_eq!(queue.remove(), Ok(1));
+    assert_eq!(queue.size(), 1);
+    queue.add(3).unwrap();
+    assert_eq!(queue.remove(), Ok(2));
+    assert_eq!(queue.remove(), Ok(3));
+    assert_eq!(queue.remove(), Err(Error::Empty));
+}
+
+#[test]
+fn test_queue_stack_lifo_order_and_ops() {
+    let ops = Rc::new(Cell::new(0));
+    let mut stack = QueueStack::from_queue(Counting::new(Queue::new(), &ops));
+
+    for i in 0..100 {
+        ops.set(0);
+        stack.add(i).unwrap();
+        // One add, then one remove and add for each older element.
+        assert_eq!(ops.get(), 1 + 2 * i);
+    }
+    for i in (0..100).rev() {
+        assert_eq!(stack.peek().unwrap(), &i);
+        assert_eq!(stack.remove().unwrap(), i);
+    }
+    assert_eq!(stack.remove(), Err(Error::Empty));
 }
 
 
@@ -101,6 +432,12 @@ fn test_identity() {
     assert_eq!(identity.get(2, 2).unwrap(), &1);
 }
 
+#[test]
+#[should_panic]
+fn test_identity_empty_panics() {
+    let _: Matrix<f64> = Matrix::identity(0);
+}
+
 #[test]
 fn test_rows() {
     let matrix: Matrix<usize> = Matrix::new(3, 6);
@@ -147,10 +484,9 @@ fn test_get_row() {
 }
 
 #[test]
-#[should_panic]
 fn test_get_row_out_of_bounds() {
-    let matrix = Matrix::from_iter(3, 6, 0..);
-    matrix.get_row(10);
+    let matrix: Matrix<i32> = Matrix::from_iter(3, 6, 0..);
+    assert_eq!(matrix.get_row(10).err(), Some(Error::OutOfBounds { index: 10, len: 3 }));
 }
 
 #[test]
@@ -161,10 +497,9 @@ fn test_get_col() {
 }
 
 #[test]
-#[should_panic]
 fn test_get_col_out_of_bounds() {
-    let matrix = Matrix::from_iter(3, 6, 0..);
-    matrix.get_col(10);
+    let matrix: Matrix<i32> = Matrix::from_iter(3, 6, 0..);
+    assert_eq!(matrix.get_col(10).err(), Some(Error::OutOfBounds { index: 10, len: 6 }));
 }
 
 #[test]
@@ -183,7 +518,7 @@ fn test_transpose() {
 #[test]
 fn test_swap_rows() {
     let mut matrix: Matrix<usize> = Matrix::from_iter(2, 2, 0..);
-    matrix.swap_rows(0, 1);
+    matrix.swap_rows(0, 1).unwrap();
     assert_eq!(matrix.get(0, 0).unwrap(), &2);
     assert_eq!(matrix.get(0, 1).unwrap(), &3);
     assert_eq!(matrix.get(1, 0).unwrap(), &0);
@@ -191,27 +526,52 @@ fn test_swap_rows() {
 }
 
 #[test]
-#[should_panic]
 fn test_swap_rows_out_of_bounds() {
     let mut matrix: Matrix<usize> = Matrix::from_iter(2, 2, 0..);
-    matrix.swap_rows(0, 3);
+    assert_eq!(matrix.swap_rows(0, 3), Err(Error::OutOfBounds { index: 3, len: 2 }));
+}
+
+#[test]
+fn test_swap_rows_non_zero() {
+    let mut matrix: Matrix<usize> = Matrix::from_iter(3, 2, 0..);
+    matrix.swap_rows(1, 2).unwrap();
+    assert_eq!(matrix, Matrix::from_vec(3, 2, vec![0, 1, 4, 5, 2, 3]));
 }
 
 
 #[test]
 fn test_swap_cols() {
     let mut matrix: Matrix<usize> = Matrix::from_iter(3, 3, 0..);
-    matrix.swap_cols(0, 2);
+    matrix.swap_cols(0, 2).unwrap();
     assert_eq!(matrix.get(0, 0).unwrap(), &2);
     assert_eq!(matrix.get(1, 1).unwrap(), &4);
     assert_eq!(matrix.get(2, 2).unwrap(), &6);
 }
 
 #[test]
-#[should_panic]
 fn test_swap_cols_out_of_bounds() {
     let mut matrix: Matrix<usize> = Matrix::from_iter(2, 2, 0..);
-    matrix.swap_cols(0, 3);
+    assert_eq!(matrix.swap_cols(0, 3), Err(Error::OutOfBounds { index: 3, len: 2 }));
+}
+
+#[test]
+fn test_swap_cols_non_square() {
+    let mut matrix: Matrix<usize> = Matrix::from_iter(2, 3, 0..);
+    matrix.swap_cols(0, 2).unwrap();
+    assert_eq!(matrix, Matrix::from_vec(2, 3, vec![2, 1, 0, 5, 4, 3]));
+}
+
+#[test]
+fn test_try_from_vec() {
+    assert!(Matrix::try_from_vec(2, 2, vec![1, 2, 3, 4]).is_ok());
+    assert_eq!(
+        Matrix::try_from_vec(2, 2, vec![1, 2, 3]),
+        Err(Error::LengthMismatch { expected: 4, found: 3 })
+    );
+    assert_eq!(
+        Matrix::<i32>::try_from_vec(3, 0, vec![]),
+        Err(Error::ZeroDimension { rows: 3, cols: 0 })
+    );
 }
 
 #[test]
@@ -233,4 +593,1370 @@ fn test_apply_mut_function() {
 fn test_macro() {
     let new_matrix = matrix![[1, 2], [3, 4]];
     assert_eq!(new_matrix.get(0, 1).unwrap(), &2);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_matrix_arithmetic_operators() {
+    let a = matrix![[1, 2, 3], [4, 5, 6]];
+    let b = matrix![[6, 5, 4], [3, 2, 1]];
+
+    assert_eq!(&a + &b, matrix![[7, 7, 7], [7, 7, 7]]);
+    assert_eq!(a.clone() - b.clone(), matrix![[-5, -3, -1], [1, 3, 5]]);
+    assert_eq!(-a.clone(), matrix![[-1, -2, -3], [-4, -5, -6]]);
+    assert_eq!(&a * 2, matrix![[2, 4, 6], [8, 10, 12]]);
+
+    // (2 x 3) * (3 x 2) = (2 x 2)
+    let product = &a * &matrix![[6, 3], [5, 2], [4, 1]];
+    assert_eq!(product, matrix![[28, 10], [73, 28]]);
+
+    let mut c = a.clone();
+    c += &b;
+    c -= b;
+    c *= 3;
+    assert_eq!(c, a.scale(3));
+
+    let mut square = matrix![[1, 1], [0, 1]];
+    square *= square.clone();
+    assert_eq!(square, matrix![[1, 2], [0, 1]]);
+}
+
+#[test]
+fn test_matrix_checked_arithmetic() {
+    let a: Matrix<i32> = Matrix::from_iter(2, 3, 0..);
+    let b: Matrix<i32> = Matrix::from_iter(3, 2, 0..);
+
+    assert_eq!(
+        a.checked_add(&b),
+        Err(Error::DimensionMismatch { expected: (2, 3), found: (3, 2) })
+    );
+    assert!(a.checked_sub(&b).is_err());
+    assert!(a.checked_mul(&a).is_err());
+    assert_eq!(a.checked_mul(&b).unwrap().rows(), 2);
+    assert_eq!(b.checked_mul(&a).unwrap().rows(), 3);
+}
+
+#[test]
+#[should_panic(expected = "expected dimensions")]
+fn test_matrix_add_mismatch_panics() {
+    let a: Matrix<i32> = Matrix::new(2, 2);
+    let b: Matrix<i32> = Matrix::new(3, 3);
+    let _ = a + b;
+}
+
+const STRATEGIES: [MulStrategy; 7] = [
+    MulStrategy::Naive,
+    MulStrategy::Ikj,
+    MulStrategy::Tiled { tile: 1 },
+    MulStrategy::Tiled { tile: 8 },
+    MulStrategy::Tiled { tile: 64 },
+    MulStrategy::Strassen { cutoff: 1 },
+    MulStrategy::Strassen { cutoff: 16 },
+];
+
+#[test]
+fn test_matrix_multiply_strategies_agree() {
+    for (m, n, p) in [(1, 1, 1), (1, 5, 1), (3, 3, 3), (8, 8, 8), (37, 53, 29), (64, 17, 65), (1, 2048, 1), (40, 3, 70)] {
+        let a = Matrix::from_vec(m, n, get_random_vec_int(m * n).into_iter().map(i64::from).collect());
+        let b = Matrix::from_vec(n, p, get_random_vec_int(n * p).into_iter().map(i64::from).collect());
+
+        let expected = a.multiply(&b, MulStrategy::Naive).unwrap();
+        assert_eq!((expected.rows(), expected.cols()), (m, p));
+        for strategy in STRATEGIES {
+            assert_eq!(a.multiply(&b, strategy).unwrap(), expected, "{:?} on {}x{}x{}", strategy, m, n, p);
+        }
+
+        let a = Matrix::from_vec(m, n, get_random_vec_int(m * n).into_iter().map(i32::unsigned_abs).collect());
+        let b = Matrix::from_vec(n, p, get_random_vec_int(n * p).into_iter().map(i32::unsigned_abs).collect());
+
+        let expected = a.multiply(&b, MulStrategy::Naive).unwrap();
+        for strategy in STRATEGIES {
+            assert_eq!(a.multiply(&b, strategy).unwrap(), expected, "{:?} on unsigned {}x{}x{}", strategy, m, n, p);
+        }
+    }
+
+    let a: Matrix<u32> = Matrix::from_iter(4, 4, 0..);
+    assert_eq!(
+        a.multiply(&a, MulStrategy::Strassen { cutoff: 1 }).unwrap(),
+        a.multiply(&a, MulStrategy::Naive).unwrap()
+    );
+}
+
+#[test]
+fn test_matrix_multiply_strategies_floats() {
+    let mut rng = thread_rng();
+    let a: Matrix<f64> = Matrix::from_iter(33, 40, (0..).map(|_| rng.gen_range(-1.0..1.0)));
+    let b: Matrix<f64> = Matrix::from_iter(40, 31, (0..).map(|_| rng.gen_range(-1.0..1.0)));
+
+    let expected = a.multiply(&b, MulStrategy::Naive).unwrap();
+    for strategy in STRATEGIES {
+        let product = a.multiply(&b, strategy).unwrap();
+        let mut max_error: f64 = 0.0;
+        for i in 0..expected.rows() {
+            for j in 0..expected.cols() {
+                max_error = max_error.max((product.get(i, j).unwrap() - expected.get(i, j).unwrap()).abs());
+            }
+        }
+        assert!(max_error < 1e-9, "{:?} differs by {}", strategy, max_error);
+    }
+}
+
+#[test]
+fn test_matrix_multiply_mismatch() {
+    let a: Matrix<i32> = Matrix::new(2, 3);
+    for strategy in STRATEGIES {
+        assert_eq!(
+            a.multiply(&a, strategy),
+            Err(Error::DimensionMismatch { expected: (3, 3), found: (2, 3) })
+        );
+    }
+}
+
+fn get_random_matrix(rows: usize, cols: usize) -> Matrix<f64> {
+    let mut rng = thread_rng();
+    Matrix::from_iter(rows, cols, (0..).map(|_| rng.gen_range(-1.0..1.0)))
+}
+
+fn assert_matrix_close(actual: &Matrix<f64>, expected: &Matrix<f64>, tolerance: f64) {
+    assert_eq!((actual.rows(), actual.cols()), (expected.rows(), expected.cols()));
+    for i in 0..expected.rows() {
+        for j in 0..expected.cols() {
+            let (a, e) = (actual.get(i, j).unwrap(), expected.get(i, j).unwrap());
+            assert!((a - e).abs() <= tolerance, "({}, {}): {} != {}", i, j, a, e);
+        }
+    }
+}
+
+fn transposed(matrix: &Matrix<f64>) -> Matrix<f64> {
+    let values = (0..matrix.cols()).flat_map(|j| (0..matrix.rows()).map(move |i| *matrix.get(i, j).unwrap()));
+    Matrix::from_iter(matrix.cols(), matrix.rows(), values)
+}
+
+#[test]
+fn test_matrix_lu_reconstructs() {
+    for n in [1, 2, 5, 20] {
+        let a = get_random_matrix(n, n);
+        let lu = a.lu().unwrap();
+
+        for i in 0..n {
+            assert_eq!(lu.l().get(i, i).unwrap(), &1.0);
+            for j in i + 1..n {
+                assert_eq!(lu.l().get(i, j).unwrap(), &0.0);
+                assert_eq!(lu.u().get(j, i).unwrap(), &0.0);
+            }
+        }
+        assert_matrix_close(&(&lu.permutation_matrix() * &a), &(lu.l() * lu.u()), 1e-12);
+    }
+}
+
+#[test]
+fn test_matrix_solve_and_inverse() {
+    let a = get_random_matrix(12, 12);
+    let b = get_random_matrix(12, 3);
+
+    let x = a.solve(&b).unwrap();
+    assert_matrix_close(&(&a * &x), &b, 1e-9);
+
+    let inverse = a.inverse().unwrap();
+    assert_matrix_close(&(&a * &inverse), &Matrix::identity(12), 1e-9);
+    assert!((a.determinant().unwrap() * inverse.determinant().unwrap() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_matrix_determinant_and_rank() {
+    let a = matrix![[2.0, -3.0, 1.0], [2.0, 0.0, -1.0], [1.0, 4.0, 5.0]];
+    assert!((a.determinant().unwrap() - 49.0).abs() < 1e-12);
+    assert_eq!(a.rank(), 3);
+
+    let singular = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+    assert!(singular.determinant().unwrap().abs() < 1e-12);
+    assert_eq!(singular.rank(), 2);
+    assert_eq!(singular.inverse(), Err(Error::Singular));
+    assert_eq!(singular.solve(&matrix![[1.0], [2.0], [3.0]]), Err(Error::Singular));
+
+    let wide = matrix![[1.0, 2.0, 3.0, 4.0], [2.0, 4.0, 6.0, 8.0]];
+    assert_eq!(wide.rank(), 1);
+    assert_eq!(matrix![[0.0, 0.0], [0.0, 0.0]].rank(), 0);
+    assert_eq!(
+        wide.lu(),
+        Err(Error::DimensionMismatch { expected: (2, 2), found: (2, 4) })
+    );
+    assert!(a.solve(&matrix![[1.0], [2.0]]).is_err());
+}
+
+#[test]
+fn test_matrix_qr_methods() {
+    for (m, n) in [(1, 1), (4, 4), (10, 3), (30, 12)] {
+        let a = get_random_matrix(m, n);
+        for method in [QrMethod::Householder, QrMethod::GramSchmidt] {
+            let qr = a.qr(method).unwrap();
+            let (q, r) = (qr.q(), qr.r());
+
+            assert_matrix_close(&(q * r), &a, 1e-12);
+            assert_matrix_close(&(&transposed(q) * q), &Matrix::identity(n), 1e-12);
+            for i in 0..n {
+                for j in 0..i {
+                    assert_eq!(r.get(i, j).unwrap(), &0.0);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_matrix_least_squares() {
+    // An exactly consistent overdetermined system has a zero residual
+    let a = get_random_matrix(20, 4);
+    let x = get_random_matrix(4, 2);
+    let b = &a * &x;
+    for method in [QrMethod::Householder, QrMethod::GramSchmidt] {
+        let fit = a.least_squares_with(&b, method).unwrap();
+        assert_matrix_close(fit.solution(), &x, 1e-10);
+        assert!(fit.residual_norms().iter().all(|norm| *norm < 1e-10));
+    }
+
+    // Otherwise the residual is orthogonal to the columns of A
+    let b = get_random_matrix(20, 1);
+    let fit = a.least_squares(&b).unwrap();
+    assert_matrix_close(&(&transposed(&a) * fit.residual()), &Matrix::new(4, 1), 1e-10);
+    let norm: f64 = (0..20).map(|i| fit.residual().get(i, 0).unwrap().powi(2)).sum::<f64>().sqrt();
+    assert!((fit.residual_norms()[0] - norm).abs() < 1e-12);
+}
+
+#[test]
+fn test_matrix_qr_errors() {
+    let wide = get_random_matrix(2, 3);
+    assert_eq!(
+        wide.qr(QrMethod::Householder),
+        Err(Error::DimensionMismatch { expected: (3, 3), found: (2, 3) })
+    );
+
+    let dependent = matrix![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    assert_eq!(dependent.qr(QrMethod::GramSchmidt), Err(Error::Singular));
+    assert_eq!(dependent.least_squares(&matrix![[1.0], [2.0], [3.0]]), Err(Error::Singular));
+    assert!(dependent.least_squares(&matrix![[1.0], [2.0]]).is_err());
+}
+
+fn get_random_spd_matrix(n: usize) -> Matrix<f64> {
+    let m = get_random_matrix(n, n);
+    &(&m * &transposed(&m)) + &Matrix::identity(n).scale(n as f64)
+}
+
+#[test]
+fn test_matrix_cholesky() {
+    for n in [1, 3, 15] {
+        let a = get_random_spd_matrix(n);
+        assert!(a.is_symmetric(0.0));
+
+        let cholesky = a.cholesky().unwrap();
+        let l = cholesky.l();
+        assert_matrix_close(&(l * &transposed(l)), &a, 1e-10);
+        for i in 0..n {
+            assert!(*l.get(i, i).unwrap() > 0.0);
+        }
+
+        let b = get_random_matrix(n, 2);
+        assert_matrix_close(&(&a * &cholesky.solve(&b).unwrap()), &b, 1e-10);
+        let determinant = a.determinant().unwrap();
+        assert!((cholesky.determinant() - determinant).abs() < 1e-9 * determinant.abs());
+    }
+}
+
+#[test]
+fn test_matrix_ldlt() {
+    let m = get_random_matrix(10, 10);
+    let a = &m + &transposed(&m);
+    let ldlt = a.ldlt().unwrap();
+
+    let mut d: Matrix<f64> = Matrix::new(10, 10);
+    for (i, d_i) in ldlt.d().iter().enumerate() {
+        d.set(i, i, *d_i);
+    }
+    let l = ldlt.l();
+    assert_matrix_close(&(&(l * &d) * &transposed(l)), &a, 1e-9);
+
+    let b = get_random_matrix(10, 1);
+    assert_matrix_close(&(&a * &ldlt.solve(&b).unwrap()), &b, 1e-8);
+    assert!((ldlt.determinant() - a.determinant().unwrap()).abs() < 1e-8 * ldlt.determinant().abs());
+}
+
+#[test]
+fn test_matrix_symmetric_errors() {
+    let asymmetric = matrix![[1.0, 0.0, 0.0], [0.0, 1.0, 2.0], [0.0, 3.0, 1.0]];
+    assert!(!asymmetric.is_symmetric(0.5));
+    assert!(asymmetric.is_symmetric(1.0));
+    assert_eq!(asymmetric.cholesky(), Err(Error::NotSymmetric { row: 1, col: 2 }));
+    assert_eq!(asymmetric.ldlt(), Err(Error::NotSymmetric { row: 1, col: 2 }));
+
+    let semidefinite = matrix![[1.0, 1.0], [1.0, 1.0]];
+    assert_eq!(semidefinite.cholesky(), Err(Error::NotPositiveDefinite { index: 1 }));
+    assert_eq!(semidefinite.ldlt(), Err(Error::Singular));
+
+    let negative = matrix![[-4.0, 0.0], [0.0, 1.0]];
+    assert_eq!(negative.cholesky(), Err(Error::NotPositiveDefinite { index: 0 }));
+    assert_eq!(negative.ldlt().unwrap().d(), &[-4.0, 1.0]);
+
+    let wide = matrix![[1.0, 2.0]];
+    assert!(!wide.is_symmetric(1.0));
+    assert!(matches!(wide.cholesky(), Err(Error::DimensionMismatch { .. })));
+}
+
+fn assert_orthonormal_columns(matrix: &Matrix<f64>, tolerance: f64) {
+    assert_matrix_close(&(&transposed(matrix) * matrix), &Matrix::identity(matrix.cols()), tolerance);
+}
+
+fn diagonal(values: &[f64]) -> Matrix<f64> {
+    let mut matrix = Matrix::new(values.len(), values.len());
+    for (i, value) in values.iter().enumerate() {
+        matrix.set(i, i, *value);
+    }
+    matrix
+}
+
+#[test]
+fn test_matrix_symmetric_eigen() {
+    for n in [1, 2, 6, 20] {
+        let m = get_random_matrix(n, n);
+        let a = &m + &transposed(&m);
+        let eigen = a.symmetric_eigen().unwrap();
+        let (values, vectors) = (eigen.values(), eigen.vectors());
+
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_orthonormal_columns(vectors, 1e-10);
+        assert_matrix_close(&(&a * vectors), &(vectors * &diagonal(values)), 1e-10);
+
+        let mut general: Vec<f64> = a.eigenvalues().unwrap().iter().map(|&(re, _)| re).collect();
+        general.reverse();
+        for (x, y) in general.iter().zip(values) {
+            assert!((x - y).abs() < 1e-9, "{} != {}", x, y);
+        }
+    }
+
+    let a = matrix![[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]];
+    let expected = [2.0 - 2f64.sqrt(), 2.0, 2.0 + 2f64.sqrt()];
+    for (value, expected) in a.symmetric_eigen().unwrap().values().iter().zip(expected) {
+        assert!((value - expected).abs() < 1e-12);
+    }
+
+    assert_eq!(matrix![[1.0, 2.0], [0.0, 1.0]].symmetric_eigen(), Err(Error::NotSymmetric { row: 0, col: 1 }));
+    assert!(matches!(matrix![[1.0, 2.0]].symmetric_eigen(), Err(Error::DimensionMismatch { .. })));
+}
+
+#[test]
+fn test_matrix_symmetric_eigen_rank_deficient() {
+    let v = get_random_matrix(30, 1);
+    let rank_one = &v * &transposed(&v);
+    let samples = get_random_matrix(5, 20);
+    let covariance = &transposed(&samples) * &samples;
+
+    for (a, rank) in [(rank_one, 1), (covariance, 5)] {
+        let eigen = a.symmetric_eigen().unwrap();
+        let (values, vectors) = (eigen.values(), eigen.vectors());
+
+        assert_orthonormal_columns(vectors, 1e-10);
+        assert_matrix_close(&(&a * vectors), &(vectors * &diagonal(values)), 1e-10);
+        let largest = values.last().unwrap().abs();
+        assert_eq!(values.iter().filter(|value| value.abs() > 1e-10 * largest).count(), rank);
+    }
+}
+
+#[test]
+fn test_matrix_eigenvalues() {
+    let triangular = matrix![[4.0, 1.0, 7.0], [0.0, -2.0, 3.0], [0.0, 0.0, 1.0]];
+    let values = triangular.eigenvalues().unwrap();
+    for (value, expected) in values.iter().zip([4.0, 1.0, -2.0]) {
+        assert!((value.0 - expected).abs() < 1e-12 && value.1 == 0.0);
+    }
+
+    // A rotation scaled by 2 about the z-axis, with eigenvalues 3 and 2e^(±iπ/3)
+    let (c, s) = (1.0, 3f64.sqrt());
+    let rotation = matrix![[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 3.0]];
+    let basis = matrix![[1.0, 2.0, 0.0], [0.0, 1.0, 1.0], [1.0, 0.0, 1.0]];
+    let a = &(&basis * &rotation) * &basis.inverse().unwrap();
+    let values = a.eigenvalues().unwrap();
+    let expected = [(3.0, 0.0), (1.0, s), (1.0, -s)];
+    for ((re, im), (expected_re, expected_im)) in values.iter().zip(expected) {
+        assert!((re - expected_re).abs() < 1e-10 && (im - expected_im).abs() < 1e-10, "{:?}", values);
+    }
+
+    // The eigenvalues of a random matrix sum to its trace and multiply to
+    // its determinant.
+    let a = get_random_matrix(12, 12);
+    let values = a.eigenvalues().unwrap();
+    let trace: f64 = (0..12).map(|i| a.get(i, i).unwrap()).sum();
+    assert!((values.iter().map(|v| v.0).sum::<f64>() - trace).abs() < 1e-9);
+    assert!(values.iter().all(|v| v.1.abs() < 1e-12 || values.iter().any(|w| w.0 == v.0 && w.1 == -v.1)));
+    let product = values.iter().fold((1.0, 0.0), |(re, im), &(x, y)| (re * x - im * y, re * y + im * x));
+    let determinant = a.determinant().unwrap();
+    assert!((product.0 - determinant).abs() < 1e-8 * determinant.abs().max(1.0) && product.1.abs() < 1e-8);
+
+    assert!(matches!(matrix![[1.0, 2.0]].eigenvalues(), Err(Error::DimensionMismatch { .. })));
+}
+
+#[test]
+fn test_matrix_power_iteration() {
+    let m = get_random_spd_matrix(8);
+    let eigen = m.symmetric_eigen().unwrap();
+    let pair = m.power_iteration(1e-10, 10_000).unwrap();
+
+    assert!((pair.value() - eigen.values()[7]).abs() < 1e-9);
+    let dot: f64 = (0..8).map(|i| pair.vector()[i] * eigen.vectors().get(i, 7).unwrap()).sum();
+    assert!((dot.abs() - 1.0).abs() < 1e-8);
+    assert!(pair.iterations() > 0);
+
+    let a = matrix![[2.0, 1.0], [1.0, 3.0]];
+    assert_eq!(a.power_iteration(1e-14, 2), Err(Error::NotConverged { iterations: 2 }));
+    assert!(matches!(matrix![[1.0, 2.0]].power_iteration(1e-10, 10), Err(Error::DimensionMismatch { .. })));
+}
+
+#[test]
+fn test_matrix_svd() {
+    for (rows, cols) in [(1, 1), (5, 5), (8, 3), (3, 8), (20, 12), (1, 4)] {
+        let a = get_random_matrix(rows, cols);
+        let svd = a.svd().unwrap();
+        let k = rows.min(cols);
+        let values = svd.singular_values();
+
+        assert_eq!((svd.u().rows(), svd.u().cols()), (rows, k));
+        assert_eq!((svd.v().rows(), svd.v().cols()), (cols, k));
+        assert!(values.windows(2).all(|pair| pair[0] >= pair[1]) && values[k - 1] >= 0.0);
+        assert_orthonormal_columns(svd.u(), 1e-10);
+        assert_orthonormal_columns(svd.v(), 1e-10);
+        assert_matrix_close(&(&(svd.u() * &diagonal(values)) * &transposed(svd.v())), &a, 1e-10);
+    }
+}
+
+#[test]
+fn test_matrix_svd_rank_deficient() {
+    let u = get_random_matrix(9, 3);
+    let v = get_random_matrix(3, 6);
+    let a = &u * &v;
+    let svd = a.svd().unwrap();
+
+    assert_eq!(svd.rank(1e-10), 3);
+    assert!(svd.singular_values()[3..].iter().all(|s| s.abs() < 1e-10));
+    assert!(svd.condition_number() > 1e10);
+    assert_orthonormal_columns(svd.u(), 1e-10);
+    assert_orthonormal_columns(svd.v(), 1e-10);
+    assert_matrix_close(&(&(svd.u() * &diagonal(svd.singular_values())) * &transposed(svd.v())), &a, 1e-10);
+
+    let zero: Matrix<f64> = Matrix::new(3, 2);
+    assert_eq!(zero.svd().unwrap().singular_values(), &[0.0, 0.0]);
+    assert!(zero.svd().unwrap().condition_number().is_nan());
+
+    // The singular values of a symmetric matrix are its absolute eigenvalues
+    let m = get_random_matrix(7, 7);
+    let a = &m + &transposed(&m);
+    let mut expected: Vec<f64> = a.symmetric_eigen().unwrap().values().iter().map(|v| v.abs()).collect();
+    expected.sort_by(|a, b| b.total_cmp(a));
+    for (s, e) in a.svd().unwrap().singular_values().iter().zip(expected) {
+        assert!((s - e).abs() < 1e-10);
+    }
+
+    let spd = get_random_spd_matrix(6);
+    let eigen = spd.symmetric_eigen().unwrap();
+    let condition = spd.svd().unwrap().condition_number();
+    assert!((condition - eigen.values()[5] / eigen.values()[0]).abs() < 1e-9 * condition);
+}
+
+fn sum_rows<M: IsMatrix<i32>>(matrix: &M) -> Vec<i32> {
+    (0..matrix.rows()).map(|row| matrix.get_row(row).unwrap().sum()).collect()
+}
+
+#[test]
+fn test_matrix_views() {
+    let matrix: Matrix<i32> = Matrix::from_iter(4, 5, 0..);
+    let view = matrix.view();
+    assert_eq!(view.to_matrix().unwrap(), matrix);
+    assert_eq!((view.offset(), view.strides()), (0, (5, 1)));
+
+    let sub = matrix.submatrix(1..4, 2..5).unwrap();
+    assert_eq!(sub.to_matrix().unwrap(), matrix![[7, 8, 9], [12, 13, 14], [17, 18, 19]]);
+    assert_eq!((sub.rows(), sub.cols(), sub.offset()), (3, 3, 7));
+    assert_eq!(sub.get(2, 0), Some(&17));
+    assert_eq!(sub.get(3, 0), None);
+    assert_eq!(sub.get_col(1).unwrap().copied().collect::<Vec<_>>(), [8, 13, 18]);
+    assert_eq!(sub.get_row(0).unwrap().len(), 3);
+    assert_eq!(sum_rows(&sub), [24, 39, 54]);
+
+    let inner = sub.submatrix(1..3, 0..2).unwrap();
+    assert_eq!(inner.to_matrix().unwrap(), matrix![[12, 13], [17, 18]]);
+    assert_eq!(inner.offset(), 12);
+
+    let transpose = sub.transpose_view();
+    assert_eq!(transpose.strides(), (1, 5));
+    assert_eq!(transpose.to_matrix().unwrap(), matrix![[7, 12, 17], [8, 13, 18], [9, 14, 19]]);
+    assert_eq!(transpose.transpose().get(2, 1), Some(&&18));
+    assert_eq!(matrix.transpose_view().to_matrix().unwrap().get_row(4).unwrap().copied().collect::<Vec<_>>(), [4, 9, 14, 19]);
+
+    let strided = matrix.view().strided(2, 3);
+    assert_eq!(strided.to_matrix().unwrap(), matrix![[0, 3], [10, 13]]);
+    assert_eq!(strided.transpose_view().strided(2, 1).to_matrix().unwrap(), matrix![[0, 10]]);
+
+    let mut sum = 0;
+    transpose.apply(|x| sum += x);
+    assert_eq!(sum, 117);
+    assert_eq!(format!("{:?}", inner), "[[12, 13], [17, 18]]");
+
+    let (top, bottom) = matrix.split_at_row(1).unwrap();
+    assert_eq!((top.rows(), bottom.rows()), (1, 3));
+    assert_eq!(bottom.get(0, 0), Some(&5));
+    let (left, right) = sub.split_at_col(3).unwrap();
+    assert_eq!((left.cols(), right.cols(), right.rows()), (3, 0, 3));
+    assert_eq!(right.get_row(0).unwrap().count(), 0);
+    assert!(matches!(right.get_col(0), Err(Error::OutOfBounds { index: 0, len: 0 })));
+    assert_eq!(right.to_matrix(), Err(Error::ZeroDimension { rows: 3, cols: 0 }));
+}
+
+#[test]
+fn test_matrix_view_errors() {
+    let matrix: Matrix<i32> = Matrix::from_iter(3, 4, 0..);
+
+    assert!(matches!(matrix.submatrix(0..4, 0..1), Err(Error::OutOfBounds { index: 4, len: 3 })));
+    assert!(matches!(matrix.submatrix(0..1, 2..5), Err(Error::OutOfBounds { index: 5, len: 4 })));
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = matrix.submatrix(2..1, 0..1);
+    assert!(matches!(reversed, Err(Error::OutOfBounds { index: 2, len: 1 })));
+    assert!(matches!(matrix.split_at_row(4), Err(Error::OutOfBounds { index: 4, len: 3 })));
+    assert!(matches!(matrix.view().split_at_col(5), Err(Error::OutOfBounds { index: 5, len: 4 })));
+
+    let view = matrix.submatrix(1..3, 1..3).unwrap();
+    assert!(matches!(view.submatrix(0..3, 0..1), Err(Error::OutOfBounds { index: 3, len: 2 })));
+    assert!(matches!(view.get_row(2), Err(Error::OutOfBounds { index: 2, len: 2 })));
+}
+
+#[test]
+#[should_panic]
+fn test_matrix_view_zero_step_panics() {
+    let matrix: Matrix<i32> = Matrix::from_iter(3, 4, 0..);
+    matrix.view().strided(0, 1);
+}
+
+#[test]
+#[should_panic(expected = "use `transpose_view` instead")]
+fn test_matrix_view_empty_transpose_panics() {
+    let matrix: Matrix<i32> = Matrix::from_iter(3, 4, 0..);
+    matrix.submatrix(2..2, 0..3).unwrap().transpose();
+}
+
+#[test]
+#[should_panic(expected = "use `transpose_view_mut` instead")]
+fn test_matrix_view_mut_empty_transpose_panics() {
+    let mut matrix: Matrix<i32> = Matrix::from_iter(3, 4, 0..);
+    let (_, empty) = matrix.split_at_row_mut(3).unwrap();
+    empty.transpose();
+}
+
+#[test]
+fn test_matrix_views_mut() {
+    let mut matrix: Matrix<i32> = Matrix::from_iter(3, 4, 0..);
+
+    let mut sub = matrix.submatrix_mut(1..3, 1..4).unwrap();
+    sub.set(0, 0, 100);
+    *sub.get_mut(1, 2).unwrap() = 200;
+    assert_eq!(sub.set(2, 0, 1), None);
+    assert_eq!(sum_rows(&sub), [100 + 6 + 7, 9 + 10 + 200]);
+    sub.swap_rows(0, 1).unwrap();
+    sub.swap_cols(0, 2).unwrap();
+    assert!(sub.swap_cols(0, 3).is_err());
+    sub.transpose_view_mut().apply_mut(|x| *x = -*x);
+    assert_eq!(sub.to_matrix().unwrap(), matrix![[-200, -10, -9], [-7, -6, -100]]);
+    assert_eq!(matrix, matrix![[0, 1, 2, 3], [4, -200, -10, -9], [8, -7, -6, -100]]);
+
+    let mut view = matrix.view_mut();
+    view.strided_mut(2, 2).apply_mut(|x| *x = 0);
+    assert_eq!(view.get_col(0).unwrap().copied().collect::<Vec<_>>(), [0, 4, 0]);
+    assert_eq!(format!("{:?}", view.submatrix_mut(0..2, 0..2).unwrap()), "[[0, 1], [4, -200]]");
+    assert_eq!(view.transpose().get(3, 2), Some(&&-100));
+    assert_eq!(view.strided_mut(2, 2).get_row(1).unwrap().copied().collect::<Vec<_>>(), [0, 0]);
+    assert_eq!(view.transpose_view_mut().get_row(3).unwrap().len(), 3);
+
+    let (left, right) = view.split_at_col_mut(4).unwrap();
+    assert_eq!(left.get_col(3).unwrap().copied().collect::<Vec<_>>(), [3, -9, -100]);
+    assert_eq!(right.get_row(2).unwrap().count(), 0);
+    assert_eq!(matrix, matrix![[0, 1, 0, 3], [4, -200, -10, -9], [0, -7, 0, -100]]);
+}
+
+#[test]
+fn test_matrix_split_mut() {
+    let mut matrix: Matrix<i32> = Matrix::new(4, 6);
+
+    let (mut top, mut bottom) = matrix.split_at_row_mut(1).unwrap();
+    top.apply_mut(|x| *x = 1);
+    let (mut left, mut right) = bottom.split_at_col_mut(2).unwrap();
+    left.apply_mut(|x| *x = 2);
+    right.set(0, 0, 3);
+    // Rows of a transposed view interleave in memory, but still split
+    // into disjoint halves.
+    let mut transpose = right.transpose_view_mut();
+    let (mut first, mut rest) = transpose.split_at_row_mut(1).unwrap();
+    first.apply_mut(|x| *x += 10);
+    rest.set(0, 2, 4);
+    top.set(0, 5, 5);
+    assert_eq!(matrix, matrix![
+        [1, 1, 1, 1, 1, 5],
+        [2, 2, 13, 0, 0, 0],
+        [2, 2, 10, 0, 0, 0],
+        [2, 2, 10, 4, 0, 0]
+    ]);
+
+    // Disjoint views can be written from different threads.
+    let (mut left, mut right) = matrix.split_at_col_mut(3).unwrap();
+    std::thread::scope(|scope| {
+        scope.spawn(|| left.apply_mut(|x| *x = 7));
+        scope.spawn(|| right.apply_mut(|x| *x *= 2));
+    });
+    assert_eq!(matrix.get_row(1).unwrap().copied().collect::<Vec<_>>(), [7, 7, 7, 0, 0, 0]);
+    assert_eq!(matrix.get_row(0).unwrap().copied().collect::<Vec<_>>(), [7, 7, 7, 2, 2, 10]);
+
+    assert!(matches!(matrix.split_at_row_mut(5), Err(Error::OutOfBounds { index: 5, len: 4 })));
+    assert!(matches!(matrix.split_at_col_mut(7), Err(Error::OutOfBounds { index: 7, len: 6 })));
+}
+
+#[test]
+fn test_matrix_index() {
+    let mut matrix: Matrix<i32> = Matrix::from_iter(3, 4, 0..);
+    for i in 0..3 {
+        for j in 0..4 {
+            assert_eq!(matrix[(i, j)], *matrix.get(i, j).unwrap());
+        }
+    }
+
+    matrix[(2, 3)] = 100;
+    matrix[(0, 0)] -= 5;
+    assert_eq!(matrix.get(2, 3), Some(&100));
+    assert_eq!(matrix[(0, 0)], -5);
+    assert_eq!(matrix.as_slice()[11], 100);
+
+    matrix.as_mut_slice().reverse();
+    assert_eq!(matrix[(0, 0)], 100);
+    assert_eq!(matrix[(2, 3)], -5);
+}
+
+#[test]
+#[should_panic(expected = "index (3, 0) is out of bounds for a 3 x 4 matrix")]
+fn test_matrix_index_out_of_bounds_panics() {
+    let matrix: Matrix<i32> = Matrix::new(3, 4);
+    let _ = matrix[(3, 0)];
+}
+
+#[test]
+#[should_panic(expected = "index (0, 4) is out of bounds for a 3 x 4 matrix")]
+fn test_matrix_index_mut_out_of_bounds_panics() {
+    let mut matrix: Matrix<i32> = Matrix::new(3, 4);
+    matrix[(0, 4)] = 1;
+}
+
+#[test]
+fn test_matrix_row_col_iterators() {
+    let mut matrix: Matrix<i32> = Matrix::from_iter(3, 4, 0..);
+
+    let rows: Vec<&[i32]> = matrix.rows_iter().collect();
+    assert_eq!(rows, [&[0, 1, 2, 3][..], &[4, 5, 6, 7], &[8, 9, 10, 11]]);
+    let cols: Vec<Vec<i32>> = matrix.cols_iter().map(|col| col.copied().collect()).collect();
+    assert_eq!(cols, [[0, 4, 8], [1, 5, 9], [2, 6, 10], [3, 7, 11]]);
+    assert_eq!(matrix.cols_iter().len(), 4);
+
+    matrix.row_mut(1).unwrap().for_each(|x| *x = -*x);
+    matrix.col_mut(2).unwrap().for_each(|x| *x *= 100);
+    assert_eq!(matrix.col_mut(3).unwrap().len(), 3);
+    assert_eq!(matrix, matrix![[0, 1, 200, 3], [-4, -5, -600, -7], [8, 9, 1000, 11]]);
+    assert!(matches!(matrix.row_mut(3), Err(Error::OutOfBounds { index: 3, len: 3 })));
+    assert!(matches!(matrix.col_mut(4), Err(Error::OutOfBounds { index: 4, len: 4 })));
+
+    let indexed: Vec<_> = matrix.iter_indexed().collect();
+    assert_eq!(indexed.len(), 12);
+    assert_eq!(indexed[6], ((1, 2), &-600));
+    assert!(matrix.iter_indexed().all(|((i, j), x)| matrix[(i, j)] == *x));
+
+    let mut column = matrix![[1], [2], [3]];
+    assert_eq!(column.rows_iter().count(), 3);
+    assert_eq!(column.col_mut(0).unwrap().count(), 3);
+    assert_eq!(column.iter_indexed().map(|(index, _)| index).collect::<Vec<_>>(), [(0, 0), (1, 0), (2, 0)]);
+
+    let (top, _) = matrix.split_at_row(2).unwrap();
+    let transpose = top.transpose();
+    assert_eq!(transpose.rows_iter().count(), transpose.rows());
+}
+
+
+// MinMaxHeap Tests
+#[test]
+fn test_min_max_heap_empty() {
+    let mut heap: MinMaxHeap<i32> = MinMaxHeap::new();
+    assert!(heap.is_empty());
+    assert_eq!(heap.peek_min(), None);
+    assert_eq!(heap.peek_max(), None);
+    assert_eq!(heap.pop_min(), None);
+    assert_eq!(heap.pop_max(), None);
+}
+
+#[test]
+fn test_min_max_heap_single() {
+    let mut heap = MinMaxHeap::from_data(vec![7]);
+    assert_eq!(heap.peek_min(), Some(&7));
+    assert_eq!(heap.peek_max(), Some(&7));
+    assert_eq!(heap.pop_max(), Some(7));
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_min_max_heap_pop_min_sorted() {
+    let data = get_random_vec_int(64);
+    let mut expected = data.clone();
+    expected.sort();
+
+    let mut heap = MinMaxHeap::from_data(data);
+    let mut result = Vec::new();
+    while let Some(value) = heap.pop_min() {
+        result.push(value);
+    }
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_min_max_heap_pop_max_sorted() {
+    let data = get_random_vec_int(64);
+    let mut expected = data.clone();
+    expected.sort_by(|a, b| b.cmp(a));
+
+    let mut heap = MinMaxHeap::new();
+    for value in data {
+        heap.push(value);
+    }
+    let mut result = Vec::new();
+    while let Some(value) = heap.pop_max() {
+        result.push(value);
+    }
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_min_max_heap_interleaved() {
+    let mut heap = MinMaxHeap::new();
+    let mut reference: Vec<i32> = Vec::new();
+
+    for (i, value) in get_random_vec_int(200).into_iter().enumerate() {
+        heap.push(value);
+        reference.push(value);
+        reference.sort();
+
+        if i % 3 == 0 {
+            assert_eq!(heap.pop_min(), Some(reference.remove(0)));
+        } else if i % 5 == 0 {
+            assert_eq!(heap.pop_max(), reference.pop());
+        }
+
+        assert_eq!(heap.len(), reference.len());
+        assert_eq!(heap.peek_min(), reference.first());
+        assert_eq!(heap.peek_max(), reference.last());
+    }
+}
+
+
+// MinHeap / MaxHeap Tests
+#[test]
+fn test_min_heap_push_validate() {
+    let mut heap = MinHeap::new();
+    for value in get_random_vec_int(100) {
+        heap.push(value);
+        assert!(heap.validate().is_ok());
+    }
+    assert_eq!(heap.len(), 100);
+}
+
+#[test]
+fn test_max_heap_from_data_validate() {
+    let heap = MaxHeap::from_data(get_random_vec_int(100));
+    assert!(heap.validate().is_ok());
+}
+
+#[test]
+fn test_heap_from_iter_and_extend() {
+    let mut heap: MaxHeap<i32> = get_random_vec_int(20).into_iter().collect();
+    heap.extend(get_random_vec_int(20));
+    assert_eq!(heap.len(), 40);
+    assert!(heap.validate().is_ok());
+
+    let mut binary: BinaryHeap<i32> = BinaryHeap::new_min();
+    binary.extend(get_random_vec_int(20));
+    assert!(binary.validate().is_ok());
+}
+
+#[test]
+fn test_heap_iter_and_into_iter() {
+    let data = get_random_vec_int(50);
+    let heap = MinHeap::from_data(data.clone());
+
+    let mut expected = data.clone();
+    expected.sort();
+
+    let mut borrowed: Vec<i32> = heap.iter().cloned().collect();
+    borrowed.sort();
+    assert_eq!(borrowed, expected);
+
+    let mut owned: Vec<i32> = heap.clone().into_iter().collect();
+    owned.sort();
+    assert_eq!(owned, expected);
+    assert_eq!((&heap).into_iter().count(), 50);
+}
+
+#[test]
+fn test_heap_display_tree() {
+    let heap = MinHeap::from_data(vec![5, 4, 3, 2, 1]);
+    assert_eq!(heap.to_string(), "1\n├── 2\n│   ├── 5\n│   └── 4\n└── 3\n");
+
+    let empty: MaxHeap<i32> = MaxHeap::default();
+    assert_eq!(empty.to_string(), "");
+}
+
+
+// BoundedHeap Tests
+#[test]
+fn test_bounded_heap_keeps_largest() {
+    let data = get_random_vec_int(100);
+    let mut expected = data.clone();
+    expected.sort_by(|a, b| b.cmp(a));
+    expected.truncate(10);
+
+    let mut top = BoundedHeap::largest(10);
+    top.extend(data);
+    assert!(top.is_full());
+    assert_eq!(top.len(), 10);
+    assert_eq!(top.into_sorted_vec(), expected);
+}
+
+#[test]
+fn test_bounded_heap_keeps_smallest() {
+    let data = get_random_vec_int(100);
+    let mut expected = data.clone();
+    expected.sort();
+    expected.truncate(10);
+
+    let mut nearest = BoundedHeap::smallest(10);
+    for value in data {
+        nearest.push(value);
+    }
+    assert_eq!(nearest.into_sorted_vec(), expected);
+}
+
+#[test]
+fn test_bounded_heap_evictions() {
+    let mut top = BoundedHeap::largest(2);
+    assert_eq!(top.push(3), None);
+    assert_eq!(top.push(1), None);
+    assert_eq!(top.push(2), Some(1));
+    assert_eq!(top.push(2), Some(2));
+    assert_eq!(top.peek_worst(), Some(&2));
+
+    let mut none: BoundedHeap<i32> = BoundedHeap::smallest(0);
+    assert_eq!(none.push(1), Some(1));
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_bounded_heap_merge() {
+    let left = get_random_vec_int(50);
+    let right = get_random_vec_int(50);
+
+    let mut expected: Vec<i32> = left.iter().chain(right.iter()).cloned().collect();
+    expected.sort();
+    expected.truncate(5);
+
+    let mut a = BoundedHeap::smallest(5);
+    a.extend(left);
+    let mut b = BoundedHeap::smallest(5);
+    b.extend(right);
+
+    a.merge(b);
+    assert_eq!(a.into_sorted_vec(), expected);
+}
+
+
+// BucketQueue / RadixHeap Tests
+fn check_monotone_pops<H: IsMonotoneHeap<usize>>(heap: &mut H) {
+    let priorities: Vec<usize> = (0..200).map(|_| thread_rng().gen_range(0..50)).collect();
+    for (value, priority) in priorities.iter().enumerate() {
+        heap.push(value, *priority).unwrap();
+    }
+
+    // Lower a third of the keys, never below zero
+    for value in (0..200).step_by(3) {
+        let current = priorities[value];
+        if current > 0 {
+            heap.decrease_key(&value, current / 2).unwrap();
+        }
+    }
+    assert_eq!(heap.len(), 200);
+
+    let mut previous = 0;
+    let mut seen = [false; 200];
+    while let Some((value, priority)) = heap.pop() {
+        assert!(priority >= previous);
+        let expected = if value % 3 == 0 { priorities[value] / 2 } else { priorities[value] };
+        assert_eq!(priority, expected);
+        assert!(!seen[value]);
+        seen[value] = true;
+        previous = priority;
+    }
+    assert!(seen.iter().all(|s| *s));
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn test_bucket_queue_monotone_pops() {
+    check_monotone_pops(&mut BucketQueue::new(50));
+}
+
+#[test]
+fn test_radix_heap_monotone_pops() {
+    check_monotone_pops(&mut RadixHeap::new());
+}
+
+#[test]
+fn test_monotone_heap_rejects_violations() {
+    let mut bucket = BucketQueue::new(10);
+    let mut radix = RadixHeap::new();
+    for heap in [&mut bucket as &mut dyn IsMonotoneHeap<&str>, &mut radix] {
+        heap.push("a", 5).unwrap();
+        heap.push("b", 8).unwrap();
+        assert_eq!(heap.push("a", 6), Err(Error::Duplicate));
+        assert_eq!(heap.decrease_key(&"c", 1), Err(Error::NotFound));
+        assert_eq!(heap.decrease_key(&"b", 9), Err(Error::PriorityNotDecreased { current: 8, priority: 9 }));
+
+        assert_eq!(heap.pop(), Some(("a", 5)));
+        assert_eq!(heap.last(), 5);
+        assert!(heap.push("c", 4).is_err());
+        assert!(heap.decrease_key(&"b", 4).is_err());
+        assert_eq!(heap.len(), 1);
+    }
+
+    assert_eq!(bucket.push("d", 16), Err(Error::PriorityOutOfRange { priority: 16, min: 5, max: 15 }));
+    assert!(radix.push("d", 1 << 40).is_ok());
+}
+
+#[test]
+fn test_monotone_heap_dijkstra() {
+    // Small weighted graph as an adjacency list of (neighbour, weight)
+    let graph: Vec<Vec<(usize, usize)>> = vec![
+        vec![(1, 4), (2, 1)],
+        vec![(3, 1)],
+        vec![(1, 2), (3, 5)],
+        vec![(4, 3)],
+        vec![],
+    ];
+
+    fn dijkstra<H: IsMonotoneHeap<usize>>(graph: &[Vec<(usize, usize)>], heap: &mut H) -> Vec<usize> {
+        let mut dist = vec![usize::MAX; graph.len()];
+        dist[0] = 0;
+        heap.push(0, 0).unwrap();
+        while let Some((node, d)) = heap.pop() {
+            for (next, weight) in &graph[node] {
+                let candidate = d + weight;
+                if candidate < dist[*next] {
+                    if dist[*next] == usize::MAX {
+                        heap.push(*next, candidate).unwrap();
+                    } else {
+                        heap.decrease_key(next, candidate).unwrap();
+                    }
+                    dist[*next] = candidate;
+                }
+            }
+        }
+        dist
+    }
+
+    assert_eq!(dijkstra(&graph, &mut BucketQueue::new(5)), vec![0, 3, 1, 4, 7]);
+    assert_eq!(dijkstra(&graph, &mut RadixHeap::new()), vec![0, 3, 1, 4, 7]);
+}
+
+
+// CircularBuffer Tests
+#[test]
+fn test_circular_buffer_overwrites_oldest() {
+    let mut buffer = CircularBuffer::new(3);
+    for i in 0..3 {
+        assert_eq!(buffer.add(i).unwrap(), None);
+    }
+    assert!(buffer.is_full());
+
+    for i in 3..10 {
+        assert_eq!(buffer.add(i).unwrap(), Some(i - 3));
+        assert_eq!(buffer.size(), 3);
+    }
+
+    assert_eq!(buffer.remove().unwrap(), 7);
+    assert_eq!(buffer.remove().unwrap(), 8);
+    assert_eq!(buffer.remove().unwrap(), 9);
+    assert_eq!(buffer.remove(), Err(Error::Empty));
+    assert_eq!(buffer.read(), Err(Error::Empty));
+}
+
+#[test]
+fn test_circular_buffer_with_default() {
+    let mut buffer = CircularBuffer::with_default(2, -1);
+    assert_eq!(buffer.size(), buffer.capacity());
+    assert_eq!(buffer.read().unwrap(), -1);
+
+    assert_eq!(buffer.add(5).unwrap(), Some(-1));
+    assert_eq!(buffer.remove().unwrap(), -1);
+    assert_eq!(buffer.remove().unwrap(), 5);
+    assert_eq!(buffer.remove().unwrap(), -1);
+    assert_eq!(buffer.size(), 2);
+}
+
+#[test]
+#[should_panic]
+fn test_circular_buffer_zero_capacity() {
+    let _buffer: CircularBuffer<i32> = CircularBuffer::new(0);
+}
+
+
+// Deque Tests
+#[test]
+fn test_deque_both_ends() {
+    let mut deque: Deque<i32> = Deque::new();
+    assert_eq!(deque.pop_front(), Err(Error::Empty));
+    assert_eq!(deque.peek_back(), Err(Error::Empty));
+
+    deque.push_back(2).unwrap();
+    deque.push_front(1).unwrap();
+    deque.push_back(3).unwrap();
+
+    assert_eq!(deque.peek_front().unwrap(), &1);
+    assert_eq!(deque.peek_back().unwrap(), &3);
+    assert_eq!(deque.get(1), Some(&2));
+    assert_eq!(deque.get(3), None);
+
+    assert_eq!(deque.pop_back().unwrap(), 3);
+    assert_eq!(deque.pop_front().unwrap(), 1);
+    assert_eq!(deque.len(), 1);
+}
+
+#[test]
+fn test_deque_rotate_split_append() {
+    let mut deque: Deque<i32> = (0..6).collect();
+    deque.rotate_left(8);
+    assert_eq!(deque.iter().cloned().collect::<Vec<_>>(), vec![2, 3, 4, 5, 0, 1]);
+    deque.rotate_right(2);
+    assert_eq!(deque, (0..6).collect());
+
+    let mut back = deque.split_off(4).unwrap();
+    assert_eq!(deque, (0..4).collect());
+    assert_eq!(back, (4..6).collect());
+    assert_eq!(deque.split_off(5).err(), Some(Error::OutOfBounds { index: 5, len: 4 }));
+
+    deque.append(&mut back);
+    assert_eq!(deque, (0..6).collect());
+    assert!(back.is_empty());
+}
+
+#[test]
+fn test_deque_adapters() {
+    fn fill_and_drain_stack<S: IsStack<i32>>(mut stack: S) -> Vec<i32> {
+        (0..4).for_each(|i| { stack.add(i).unwrap(); });
+        (0..4).map(|_| stack.remove().unwrap()).collect()
+    }
+
+    fn fill_and_drain_queue<Q: IsQueue<i32>>(mut queue: Q) -> Vec<i32> {
+        (0..4).for_each(|i| { queue.add(i).unwrap(); });
+        (0..4).map(|_| queue.remove().unwrap()).collect()
+    }
+
+    assert_eq!(fill_and_drain_stack(AsStack(Deque::new())), vec![3, 2, 1, 0]);
+    assert_eq!(fill_and_drain_queue(AsQueue(Deque::new())), vec![0, 1, 2, 3]);
+
+    let mut deque: Deque<i32> = Deque::new();
+    let mut stack = AsStack(&mut deque);
+    stack.add(7).unwrap();
+    assert_eq!(stack.read().unwrap(), 7);
+    assert_eq!(deque.len(), 1);
+}
+
+
+
+// Sparse Tests
+
+fn get_random_sparse_matrix(rows: usize, cols: usize, density: f64) -> Matrix<i64> {
+    let mut rng = thread_rng();
+    let values = (0..).map(|_| if rng.gen_bool(density) { rng.gen_range(-9..10) } else { 0 });
+    Matrix::from_iter(rows, cols, values)
+}
+
+#[test]
+fn test_sparse_conversions() {
+    for (rows, cols) in [(1, 1), (4, 7), (30, 20)] {
+        let dense = get_random_sparse_matrix(rows, cols, 0.2);
+        let csr = CsrMatrix::from_dense(&dense);
+        let csc = CscMatrix::from_dense(&dense);
+
+        let mut nnz = 0;
+        dense.apply(|&x| if x != 0 { nnz += 1 });
+        assert_eq!((csr.nnz(), csc.nnz()), (nnz, nnz));
+        assert_eq!((csr.rows(), csr.cols(), csc.rows(), csc.cols()), (rows, cols, rows, cols));
+        assert_eq!(csr.row_offsets().len(), rows + 1);
+        assert_eq!(csc.col_offsets().len(), cols + 1);
+
+        assert_eq!(csr.to_dense(), dense);
+        assert_eq!(csc.to_dense(), dense);
+        assert_eq!(csr.to_csc(), csc);
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(CooMatrix::from(&csr).to_csc(), csc);
+        assert_eq!(CooMatrix::from(&csc).to_csr(), csr);
+
+        for i in 0..rows {
+            for j in 0..cols {
+                let expected = Some(dense.get(i, j).unwrap()).filter(|&&x| x != 0);
+                assert_eq!(csr.get(i, j), expected);
+                assert_eq!(csc.get(i, j), expected);
+            }
+        }
+        assert_eq!(csr.get(rows, 0), None);
+        assert_eq!(csc.get(0, cols), None);
+    }
+}
+
+#[test]
+fn test_sparse_coo_builder() {
+    let mut coo = CooMatrix::with_capacity(3, 4, 6);
+    assert!(coo.is_empty());
+    coo.push(2, 3, 1).unwrap();
+    coo.push(0, 1, 4).unwrap();
+    coo.push(2, 3, 2).unwrap();
+    coo.push(1, 0, 5).unwrap();
+    coo.push(1, 0, -5).unwrap();
+    coo.push(0, 0, 0).unwrap();
+    assert_eq!(coo.push(3, 0, 1), Err(Error::OutOfBounds { index: 3, len: 3 }));
+    assert_eq!(coo.push(0, 4, 1), Err(Error::OutOfBounds { index: 4, len: 4 }));
+    assert_eq!(coo.len(), 6);
+    assert_eq!(coo.iter().next(), Some(&(2, 3, 1)));
+
+    let dense = matrix![[0, 4, 0, 0], [0, 0, 0, 0], [0, 0, 0, 3]];
+    assert_eq!(coo.to_dense(), dense);
+    assert_eq!(coo.to_csr(), CsrMatrix::from_dense(&dense));
+    assert_eq!(coo.to_csc(), CscMatrix::from_dense(&dense));
+    assert_eq!(coo.to_csr().col_indices(), &[1, 3]);
+    assert_eq!(coo.to_csc().row_indices(), &[0, 2]);
+    assert_eq!(coo.to_csr().values(), coo.to_csc().values());
+}
+
+#[test]
+fn test_sparse_iterators() {
+    let dense = get_random_sparse_matrix(12, 9, 0.3);
+    let csr = CsrMatrix::from_dense(&dense);
+    let csc = CscMatrix::from_dense(&dense);
+
+    for row in 0..12 {
+        let expected: Vec<_> = dense.get_row(row).unwrap().enumerate().filter(|(_, &x)| x != 0).collect();
+        assert_eq!(csr.get_row(row).unwrap().collect::<Vec<_>>(), expected);
+        assert_eq!(csc.get_row(row).unwrap().collect::<Vec<_>>(), expected);
+    }
+    for col in 0..9 {
+        let expected: Vec<_> = dense.get_col(col).unwrap().enumerate().filter(|(_, &x)| x != 0).collect();
+        assert_eq!(csr.get_col(col).unwrap().collect::<Vec<_>>(), expected);
+        assert_eq!(csc.get_col(col).unwrap().collect::<Vec<_>>(), expected);
+    }
+
+    assert!(matches!(csr.get_row(12), Err(Error::OutOfBounds { index: 12, len: 12 })));
+    assert!(matches!(csr.get_col(9), Err(Error::OutOfBounds { index: 9, len: 9 })));
+    assert!(matches!(csc.get_row(12), Err(Error::OutOfBounds { index: 12, len: 12 })));
+    assert!(matches!(csc.get_col(9), Err(Error::OutOfBounds { index: 9, len: 9 })));
+}
+
+#[test]
+fn test_sparse_transpose() {
+    let dense = get_random_sparse_matrix(8, 5, 0.3);
+    let transpose = Matrix::from_iter(5, 8, (0..5).flat_map(|col| dense.get_col(col).unwrap().copied().collect::<Vec<_>>()));
+
+    assert_eq!(CsrMatrix::from_dense(&dense).transpose(), CsrMatrix::from_dense(&transpose));
+    assert_eq!(CscMatrix::from_dense(&dense).transpose(), CscMatrix::from_dense(&transpose));
+    assert_eq!(CsrMatrix::from_dense(&dense).transpose().transpose(), CsrMatrix::from_dense(&dense));
+}
+
+#[test]
+fn test_sparse_multiply() {
+    for (m, k, n) in [(1, 1, 1), (6, 4, 9), (25, 30, 20)] {
+        let a = get_random_sparse_matrix(m, k, 0.25);
+        let b = get_random_sparse_matrix(k, n, 0.25);
+        let expected = &a * &b;
+        let (a_csr, b_csr) = (CsrMatrix::from_dense(&a), CsrMatrix::from_dense(&b));
+        let (a_csc, b_csc) = (CscMatrix::from_dense(&a), CscMatrix::from_dense(&b));
+
+        assert_eq!(&a_csr * &b, expected);
+        assert_eq!(&a_csc * &b, expected);
+        assert_eq!(&a_csr * &b_csr, CsrMatrix::from_dense(&expected));
+        assert_eq!(&a_csc * &b_csc, CscMatrix::from_dense(&expected));
+
+        let x: Vec<i64> = (0..k as i64).collect();
+        let column = Matrix::from_vec(k, 1, x.clone());
+        let expected: Vec<i64> = (&a * &column).get_col(0).unwrap().copied().collect();
+        assert_eq!(a_csr.mul_vec(&x).unwrap(), expected);
+        assert_eq!(a_csc.mul_vec(&x).unwrap(), expected);
+    }
+
+    // Products that cancel are not stored
+    let a = CsrMatrix::from_dense(&matrix![[1, 1]]);
+    let b = CsrMatrix::from_dense(&matrix![[1], [-1]]);
+    assert_eq!((&a * &b).nnz(), 0);
+}
+
+#[test]
+fn test_sparse_multiply_errors() {
+    let a = CsrMatrix::from_dense(&matrix![[1, 0, 2], [0, 3, 0]]);
+    let mismatch = Error::DimensionMismatch { expected: (3, 3), found: (2, 3) };
+
+    assert_eq!(a.mul_sparse(&a), Err(mismatch));
+    assert_eq!(a.mul_dense(&matrix![[1, 2, 3], [4, 5, 6]]), Err(mismatch));
+    assert_eq!(a.to_csc().mul_sparse(&a.to_csc()), Err(mismatch));
+    assert_eq!(a.mul_vec(&[1, 2]), Err(Error::DimensionMismatch { expected: (3, 1), found: (2, 1) }));
+    assert_eq!(a.to_csc().mul_vec(&[1]), Err(Error::DimensionMismatch { expected: (3, 1), found: (1, 1) }));
+}
+
+#[test]
+#[should_panic]
+fn test_sparse_multiply_mismatch_panics() {
+    let a = CsrMatrix::from_dense(&matrix![[1, 0, 2], [0, 3, 0]]);
+    let _ = &a * &a;
+}
+
+/// Builds the 5-point finite difference Laplacian on an `n` x `n` grid, plus
+/// `shift` on the diagonal, with `convection` added to the east neighbour
+/// and subtracted from the west one.
+fn get_laplacian(n: usize, shift: f64, convection: f64) -> CsrMatrix<f64> {
+    let mut coo = CooMatrix::new(n * n, n * n);
+    for i in 0..n {
+        for j in 0..n {
+            let row = i * n + j;
+            coo.push(row, row, 4.0 + shift).unwrap();
+            if i > 0 {
+                coo.push(row, row - n, -1.0).unwrap();
+            }
+            if i + 1 < n {
+                coo.push(row, row + n, -1.0).unwrap();
+            }
+            if j > 0 {
+                coo.push(row, row - 1, -1.0 - convection).unwrap();
+            }
+            if j + 1 < n {
+                coo.push(row, row + 1, -1.0 + convection).unwrap();
+            }
+        }
+    }
+    coo.to_csr()
+}
+
+fn assert_solves<A: IsLinearOperator>(a: &A, b: &[f64], result: &IterativeSolution, tolerance: f64) {
+    let b_norm = b.iter().map(|b| b * b).sum::<f64>().sqrt();
+    let residual = a.apply(result.solution()).iter().zip(b).map(|(ax, b)| (b - ax).powi(2)).sum::<f64>().sqrt();
+
+    assert!(residual <= tolerance * b_norm * 1.01, "{} > {}", residual, tolerance * b_norm);
+    assert_eq!(result.residuals().len(), result.iterations() + 1);
+    assert!((result.residuals()[0] - b_norm).abs() < 1e-12);
+    assert!((result.residual() - residual).abs() <= 1e-6 * b_norm);
+}
+
+#[test]
+fn test_sparse_solvers() {
+    let a = get_laplacian(8, 0.5, 0.0);
+    let b: Vec<f64> = get_random_matrix(64, 1).get_col(0).unwrap().copied().collect();
+    let options = SolverOptions { tolerance: 1e-10, max_iterations: 2000, restart: 20 };
+    let jacobi = JacobiPreconditioner::new(&a).unwrap();
+
+    let results = [
+        a.conjugate_gradient(&b, &options, &IdentityPreconditioner).unwrap(),
+        a.conjugate_gradient(&b, &options, &jacobi).unwrap(),
+        a.jacobi(&b, &options).unwrap(),
+        a.gauss_seidel(&b, &options).unwrap(),
+        a.gmres(&b, &options, &IdentityPreconditioner).unwrap(),
+        a.gmres(&b, &options, &jacobi).unwrap(),
+    ];
+    for result in &results {
+        assert_solves(&a, &b, result, options.tolerance);
+        for (x, y) in result.solution().iter().zip(results[0].solution()) {
+            assert!((x - y).abs() < 1e-8);
+        }
+    }
+    assert!(results[3].iterations() < results[2].iterations());
+    assert!(results[0].iterations() < results[3].iterations());
+
+    // The same system in dense and column-major form
+    let dense = a.to_dense();
+    let csc = a.to_csc();
+    assert_eq!(dense.conjugate_gradient(&b, &options, &jacobi).unwrap().iterations(), results[1].iterations());
+    assert_solves(&dense, &b, &dense.gauss_seidel(&b, &options).unwrap(), options.tolerance);
+    assert_solves(&csc, &b, &csc.gauss_seidel(&b, &options).unwrap(), options.tolerance);
+    assert_solves(&csc, &b, &csc.gmres(&b, &options, &jacobi).unwrap(), options.tolerance);
+}
+
+#[test]
+fn test_sparse_solvers_preconditioning() {
+    // A badly scaled diagonal system is solved in one step once
+    // preconditioned.
+    let mut coo = CooMatrix::new(50, 50);
+    for i in 0..50 {
+        coo.push(i, i, 10f64.powi(i as i32 % 7)).unwrap();
+    }
+    let a = coo.to_csr();
+    let b = vec![1.0; 50];
+    let options = SolverOptions::default();
+    let jacobi = JacobiPreconditioner::new(&a).unwrap();
+
+    assert_eq!(a.conjugate_gradient(&b, &options, &jacobi).unwrap().iterations(), 1);
+    assert_eq!(a.gmres(&b, &options, &jacobi).unwrap().iterations(), 1);
+    assert!(a.conjugate_gradient(&b, &options, &IdentityPreconditioner).unwrap().iterations() > 1);
+}
+
+#[test]
+fn test_sparse_gmres_nonsymmetric() {
+    let a = get_laplacian(10, 0.0, 0.6);
+    let b: Vec<f64> = (0..100).map(|i| (i as f64).sin()).collect();
+    let jacobi = JacobiPreconditioner::new(&a).unwrap();
+
+    for restart in [5, 30, 100] {
+        let options = SolverOptions { restart, ..SolverOptions::default() };
+        let result = a.gmres(&b, &options, &jacobi).unwrap();
+        assert_solves(&a, &b, &result, options.tolerance);
+        assert!(result.residuals().windows(2).all(|pair| pair[1] <= pair[0] * (1.0 + 1e-9)));
+    }
+
+    // Without restarts, GMRES finishes within the size of the system
+    let options = SolverOptions { restart: 100, ..SolverOptions::default() };
+    assert!(a.gmres(&b, &options, &IdentityPreconditioner).unwrap().iterations() <= 100);
+}
+
+#[test]
+fn test_sparse_solver_errors() {
+    let a = get_laplacian(4, 0.0, 0.0);
+    let b = vec![1.0; 16];
+    let options = SolverOptions::default();
+
+    let few = SolverOptions { max_iterations: 2, ..options };
+    assert_eq!(a.conjugate_gradient(&b, &few, &IdentityPreconditioner), Err(Error::NotConverged { iterations: 2 }));
+    assert_eq!(a.jacobi(&b, &few), Err(Error::NotConverged { iterations: 2 }));
+    assert_eq!(a.gauss_seidel(&b, &few), Err(Error::NotConverged { iterations: 2 }));
+    assert_eq!(a.gmres(&b, &few, &IdentityPreconditioner), Err(Error::NotConverged { iterations: 2 }));
+
+    let zero = a.conjugate_gradient(&[0.0; 16], &options, &IdentityPreconditioner).unwrap();
+    assert_eq!((zero.iterations(), zero.solution()), (0, &[0.0; 16][..]));
+
+    let mismatch = Err(Error::DimensionMismatch { expected: (16, 1), found: (3, 1) });
+    assert_eq!(a.jacobi(&[1.0; 3], &options), mismatch);
+    assert_eq!(a.gmres(&[1.0; 3], &options, &IdentityPreconditioner), mismatch);
+
+    let wide = matrix![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+    let not_square = Err(Error::DimensionMismatch { expected: (2, 2), found: (2, 3) });
+    assert_eq!(wide.gauss_seidel(&[1.0; 2], &options), not_square);
+    assert_eq!(wide.conjugate_gradient(&[1.0; 2], &options, &IdentityPreconditioner), not_square);
+
+    let zero_diagonal = CsrMatrix::from_dense(&matrix![[0.0, 1.0], [1.0, 0.0]]);
+    assert_eq!(JacobiPreconditioner::new(&zero_diagonal), Err(Error::Singular));
+    assert_eq!(zero_diagonal.jacobi(&[1.0, 1.0], &options), Err(Error::Singular));
+    assert_eq!(zero_diagonal.gauss_seidel(&[1.0, 1.0], &options), Err(Error::Singular));
+    let swap = zero_diagonal.gmres(&[1.0, 2.0], &options, &IdentityPreconditioner).unwrap();
+    assert!((swap.solution()[0] - 2.0).abs() < 1e-12 && (swap.solution()[1] - 1.0).abs() < 1e-12);
+
+    let indefinite = matrix![[1.0, 0.0], [0.0, -1.0]];
+    assert_eq!(
+        indefinite.conjugate_gradient(&[1.0, 1.0], &options, &IdentityPreconditioner),
+        Err(Error::NotPositiveDefinite { index: 0 })
+    );
+}
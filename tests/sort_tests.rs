@@ -3,13 +3,11 @@ use rand::{thread_rng, Rng};
 
 // Test Arrays and Solutions
 fn get_random_array_float() -> [f64; 32] {
-    let array = [(); 32].map(|_| thread_rng().gen_range(-100.0..100.0));
-    array
+    [(); 32].map(|_| thread_rng().gen_range(-100.0..100.0))
 }
 
 fn get_random_array_int() -> [i32; 32] {
-    let array = [(); 32].map(|_| thread_rng().gen_range(-100..100));
-    array
+    [(); 32].map(|_| thread_rng().gen_range(-100..100))
 }
 
 fn verify_asc<T: PartialOrd>(array: &[T]) -> bool {